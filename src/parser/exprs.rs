@@ -1,10 +1,11 @@
 use nom::{
 	IResult, Parser,
-	character::complete::multispace1,
+	character::complete::{multispace1, satisfy},
 	branch::alt,
 	bytes::complete::tag,
 	multi::many0,
 	character::complete::not_line_ending,
+	combinator::{not, opt},
 	sequence::delimited
 };
 
@@ -17,50 +18,94 @@ use self::{
 	literals::{
 		bool::Bool,
 		char::Char,
+		escape::failure,
 		num::Num,
 		string::String
 	},
+	array_lit::ArrayLit,
 	assign::Assign,
+	attr::Attr,
+	binary::Binary,
 	block::Block,
 	box_decl::BoxDecl,
+	r#break::{Break, Continue},
+	builtin::Builtin,
+	cast::Cast,
+	catch::Catch,
 	enum_decl::EnumDecl,
+	error_set::ErrorSet,
 	fn_call::FnCall,
 	fn_decl::FnDecl,
+	fn_expr::FnExpr,
 	ident::Ident,
+	interface_decl::InterfaceDecl,
 	r#if::If,
+	index::{Index, Slice},
 	r#loop::Loop,
+	r#match::Match,
 	path::Path,
+	postfix::{Postfix, PostfixParser},
+	range::Range,
 	r#return::Return,
 	struct_decl::StructDecl,
 	struct_val::StructVal,
+	tuple::{Tuple, TupleField},
+	type_decl::TypeDecl,
+	union_decl::UnionDecl,
 	r#type::Type,
+	unary::Unary,
 	vis::Vis,
 };
 
+pub mod array_lit;
 pub mod assign;
+pub mod attr;
+pub mod binary;
 pub mod block;
 pub mod box_decl;
+pub mod r#break;
+pub mod builtin;
+pub mod cast;
+pub mod catch;
 pub mod enum_decl;
+pub mod error_set;
 pub mod file;
 pub mod fn_call;
 pub mod fn_decl;
+pub mod fn_expr;
 pub mod ident;
 pub mod r#if;
+pub mod index;
+pub mod interface_decl;
 pub mod literals;
 pub mod r#loop;
+pub mod r#match;
 pub mod path;
+pub mod pattern;
+pub mod postfix;
+pub mod range;
 pub mod r#return;
 pub mod struct_decl;
 pub mod struct_val;
+pub mod tuple;
 pub mod r#type;
+pub mod type_decl;
+pub mod unary;
+pub mod union_decl;
 pub mod vis;
 
-pub const RESERVED: [&str; 13] = [
-	"box"   , "else"  , "enum"  ,
-	"false" , "fn"    , "if"    ,
-	"loop"  , "match" , "mut"   ,
-	"pub"   , "return", "struct",
-	"true"  ,
+pub const RESERVED: [&str; 31] = [
+	"and"     , "as"    , "box"   ,
+	"break"   , "catch" , "comp"  ,
+	"continue", "distinct", "dyn" ,
+	"else"    , "enum"  , "error" ,
+	"extern"  , "false" , "fn"    ,
+	"if"      , "in"    , "interface",
+	"loop"    , "match" , "mut"   ,
+	"null"    , "or"    , "orelse",
+	"pub"     , "return", "struct",
+	"true"    , "try"   , "type"  ,
+	"union"   ,
 ];
 
 
@@ -70,6 +115,16 @@ pub fn ignore_comment(input: &str) -> IResult<&str, (), ErrorTree<&str>> {
 		.map(|(input, _)| (input, ()))
 }
 
+/// Matches `word` only when it is not directly followed by an identifier
+/// character, so `or` does not match the start of `order`.
+pub fn keyword<'a>(word: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, ErrorTree<&'a str>> {
+	move |input| {
+		tag(word)
+			.terminated(not(satisfy(|c| c.is_alphanumeric() || c == '_')))
+			.parse(input)
+	}
+}
+
 pub fn ws<'a, O, F>(f: F) -> impl FnMut(&'a str) -> IResult<&'a str, O, ErrorTree<&'a str>>
 where
 	F: Parser<&'a str, O, ErrorTree<&'a str>>
@@ -88,48 +143,158 @@ pub enum Expr<'a> {
 	// Literals
 	Bool(Bool),
 	Char(Char<'a>),
+	Null,
 	Num(Num<'a>),
 	String(String<'a>),
 
 	// ----------
+	ArrayLit(Box<ArrayLit<'a>>),
 	Assign(Box<Assign<'a>>),
+	Binary(Box<Binary<'a>>),
 	Block(Box<Block<'a>>),
 	BoxDecl(Box<BoxDecl<'a>>),
-	EnumDecl(EnumDecl<'a>),
+	Break(Box<Break<'a>>),
+	Builtin(Box<Builtin<'a>>),
+	Cast(Box<Cast<'a>>),
+	Catch(Box<Catch<'a>>),
+	Continue(Continue<'a>),
+	EnumDecl(Box<EnumDecl<'a>>),
+	ErrorSet(ErrorSet<'a>),
+	Fn(Box<FnExpr<'a>>),
 	FnCall(Box<FnCall<'a>>),
 	FnDecl(Box<FnDecl<'a>>),
 	Ident(Ident<'a>),
 	If(Box<If<'a>>),
+	Index(Box<Index<'a>>),
+	InterfaceDecl(Box<InterfaceDecl<'a>>),
 	Loop(Box<Loop<'a>>),
-	Path(Path<'a>),
+	Match(Box<Match<'a>>),
+	Path(Box<Path<'a>>),
+	Range(Box<Range<'a>>),
 	Return(Box<Return<'a>>),
+	Slice(Box<Slice<'a>>),
 	StructDecl(StructDecl<'a>),
 	StructVal(Box<StructVal<'a>>),
+	Tuple(Box<Tuple<'a>>),
+	TupleField(Box<TupleField<'a>>),
 	Type(Box<Type<'a>>),
+	TypeDecl(Box<TypeDecl<'a>>),
+	Unary(Box<Unary<'a>>),
+	UnionDecl(Box<UnionDecl<'a>>),
 }
 
 impl<'a> Expr<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (rest, expr) = Range::parse_expr(input, Postfix::parse)?;
+
+		// A declaration is never assigned to, so what follows it is left to
+		// the enclosing parser rather than reported as a non-place.
+		if StructDecl::is_decl(&expr) {
+			return Ok((rest, expr));
+		}
+
+		let (rest, op) = match opt(ws(Assign::parse_op))(rest)? {
+			(rest, Some(op)) => (rest, op),
+			(rest, None) => return Ok((rest, expr))
+		};
+
+		if !Assign::is_place(&expr) {
+			return Err(failure(input, "left-hand side of an assignment must be a variable, field, index, slice, dereference or a tuple of those"));
+		}
+
+		ws(Self::parse)
+			.parse(rest)
+			.map(|(input, value)| (input, Expr::Assign(Box::new(Assign { op, expr, value }))))
+	}
+
+	/// Parses an expression directly followed by a block, such as the
+	/// condition of an `if`. Struct literals are only allowed between
+	/// parentheses there, so that `if x { y }` is not read as `if (x { y })`.
+	pub fn parse_cond(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		Range::parse_expr(input, Postfix::parse_cond)
+	}
+
+	/// Parses a prefix operator applied to an operand, or a postfix
+	/// expression.
+	pub fn parse_unary(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Self, ErrorTree<&'a str>> {
+		alt((
+			(|input| Unary::parse(input, postfix)).map(|x| Expr::Unary(Box::new(x))),
+			|input| Self::parse_postfix(input, postfix)
+		))(input)
+	}
+
+	/// Parses a primary expression followed by any number of operations
+	/// parsed by `postfix`, `a.b(c)[d]` being parsed as `((a.b)(c))[d]`.
+	pub fn parse_postfix(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Self, ErrorTree<&'a str>> {
+		Postfix::parse_chain(Self::parse_primary, postfix)(input)
+	}
+
+	/// Parses an expression that does not start with an operator.
+	pub fn parse_primary(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		alt((
 			// ----------
-			Assign::parse.map(|x| Expr::Assign(Box::new(x))),
+			Tuple::parse_expr,
+			ArrayLit::parse.map(|x| Expr::ArrayLit(Box::new(x))),
 			Block::parse.map(|x| Expr::Block(Box::new(x))),
-			BoxDecl::parse.map(|x| Expr::BoxDecl(Box::new(x))),
-			EnumDecl::parse.map(|x| Expr::EnumDecl(x)),
+			Break::parse.map(|x| Expr::Break(Box::new(x))),
+			Self::parse_decl,
+			Continue::parse.map(|x| Expr::Continue(x)),
+			ErrorSet::parse.map(|x| Expr::ErrorSet(x)),
 			If::parse.map(|x| Expr::If(Box::new(x))),
 			Loop::parse.map(|x| Expr::Loop(Box::new(x))),
-			Path::parse_fn_call.map(|x| Expr::Path(x)),
+			Match::parse.map(|x| Expr::Match(Box::new(x))),
 			Return::parse.map(|x| Expr::Return(Box::new(x))),
-			FnCall::parse.map(|x| Expr::FnCall(Box::new(x))),
+			FnExpr::parse.map(|x| Expr::Fn(Box::new(x))),
+			StructVal::parse.map(|x| Expr::StructVal(Box::new(x))),
+			Self::parse_literal,
+			Ident::parse.map(|x| Expr::Ident(x)),
+		))(input)
+	}
+
+	/// Parses a declaration of a box, function, type or interface, with the
+	/// attributes preceding it. A builtin call is written like a lone
+	/// attribute, so one that no declaration follows is read as a builtin
+	/// here, without parsing its inputs a second time.
+	pub fn parse_decl(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (rest, attrs) = Attr::parse_all(input)?;
+
+		let (rest, mut decl) = match alt((
+			BoxDecl::parse.map(|x| Expr::BoxDecl(Box::new(x))),
+			EnumDecl::parse.map(|x| Expr::EnumDecl(Box::new(x))),
 			FnDecl::parse.map(|x| Expr::FnDecl(Box::new(x))),
+			InterfaceDecl::parse.map(|x| Expr::InterfaceDecl(Box::new(x))),
 			StructDecl::parse.map(|x| Expr::StructDecl(x)),
-			StructVal::parse.map(|x| Expr::StructVal(Box::new(x))),
+			TypeDecl::parse.map(|x| Expr::TypeDecl(Box::new(x))),
+			UnionDecl::parse.map(|x| Expr::UnionDecl(Box::new(x))),
+		))(rest) {
+			Err(nom::Err::Error(e)) => {
+				return match Builtin::from_attrs(input, attrs) {
+					Some(builtin) => Ok((rest, Expr::Builtin(Box::new(builtin)))),
+					None => Err(nom::Err::Error(e))
+				};
+			},
+			result => result?
+		};
 
-			Path::parse_ident.map(|x| Expr::Path(x)),
+		match &mut decl {
+			Expr::BoxDecl(x) => x.attrs = attrs,
+			Expr::EnumDecl(x) => x.attrs = attrs,
+			Expr::FnDecl(x) => x.attrs = attrs,
+			Expr::InterfaceDecl(x) => x.attrs = attrs,
+			Expr::StructDecl(x) => x.attrs = attrs,
+			Expr::TypeDecl(x) => x.attrs = attrs,
+			Expr::UnionDecl(x) => x.attrs = attrs,
+			_ => unreachable!()
+		}
 
-			// Literals
+		Ok((rest, decl))
+	}
+
+	pub fn parse_literal(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
 			Bool::parse.map(|x| Expr::Bool(x)),
 			Char::parse.map(|x| Expr::Char(x)),
+			keyword("null").map(|_| Expr::Null),
 			Num::parse.map(|x| Expr::Num(x)),
 			String::parse.map(|x| Expr::String(x)),
 		))(input)
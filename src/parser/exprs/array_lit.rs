@@ -0,0 +1,53 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	sequence::{pair, delimited},
+	combinator::opt,
+	multi::many0
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, ws};
+
+#[derive(Debug, Clone)]
+pub enum ArrayLit<'a> {
+	/// `[a, b, c]`
+	Elems(Vec<Expr<'a>>),
+	/// `[value; count]`
+	Repeat {
+		value: Box<Expr<'a>>,
+		count: Box<Expr<'a>>
+	}
+}
+
+impl<'a> ArrayLit<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		delimited(
+			tag("["),
+			ws(opt(pair(
+				ws(Expr::parse),
+				alt((
+					ws(Expr::parse).preceded_by(tag(";")).map(|x| Err(x)),
+					many0(ws(Expr::parse).preceded_by(tag(","))).map(|x| Ok(x))
+				))
+			))),
+			tag("]")
+		)
+			.parse(input)
+			.map(|(input, array)| {
+				match array {
+					Some((value, Err(count))) => (input, ArrayLit::Repeat { value: Box::new(value), count: Box::new(count) }),
+					Some((first, Ok(mut elems))) => {
+						elems.insert(0, first);
+						(input, ArrayLit::Elems(elems))
+					},
+					None => (input, ArrayLit::Elems(Vec::new()))
+				}
+			})
+	}
+}
@@ -1,23 +1,45 @@
-use nom::{IResult, sequence::tuple, Parser};
+use nom::{IResult, branch::alt, combinator::not, Parser};
 use nom_supreme::{error::ErrorTree, ParserExt, tag::complete::tag};
 
-use super::{Expr, ws, path::Path};
+use super::{Expr, binary::BinaryOp, unary::UnaryOp};
 
+/// An assignment to a place, either plain (`x = y`) or compound
+/// (`x += y`), a compound assignment being equivalent to `x = x + y` with
+/// `x` evaluated once.
 #[derive(Debug, Clone)]
 pub struct Assign<'a> {
+    /// The operator of a compound assignment, `None` for `=`.
+    pub op: Option<BinaryOp>,
     pub expr: Expr<'a>,
     pub value: Expr<'a>
 }
 
 impl<'a> Assign<'a> {
-    pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-        tuple((
-            ws(Path::parse).map(|x| Expr::Path(x)).terminated(tag("=")),
-            ws(Expr::parse)
-        ))
-            .parse(input)
-            .map(|(input, (expr, value))| {
-                (input, Self { expr, value })
-            })
+    /// Parses the operator following the assigned expression.
+    pub fn parse_op(input: &str) -> IResult<&str, Option<BinaryOp>, ErrorTree<&str>> {
+        alt((
+            tag("=").terminated(not(alt((tag("="), tag(">"))))).map(|_| None),
+            tag("+=").map(|_| Some(BinaryOp::Add)),
+            tag("-=").map(|_| Some(BinaryOp::Sub)),
+            tag("*=").map(|_| Some(BinaryOp::Mul)),
+            tag("/=").map(|_| Some(BinaryOp::Div)),
+            tag("%=").map(|_| Some(BinaryOp::Rem)),
+            tag("&=").map(|_| Some(BinaryOp::BitAnd)),
+            tag("|=").map(|_| Some(BinaryOp::BitOr)),
+            tag("^=").map(|_| Some(BinaryOp::BitXor)),
+            tag("<<=").map(|_| Some(BinaryOp::Shl)),
+            tag(">>=").map(|_| Some(BinaryOp::Shr))
+        ))(input)
     }
-}
\ No newline at end of file
+
+    /// Whether `expr` designates a place that can be assigned to. A tuple of
+    /// places is one too, `(a, b) = (b, a)` assigning each element.
+    pub fn is_place(expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(_) | Expr::Path(_) | Expr::TupleField(_) | Expr::Index(_) | Expr::Slice(_) => true,
+            Expr::Tuple(tuple) => tuple.exprs.iter().all(Self::is_place),
+            Expr::Unary(unary) => unary.op == UnaryOp::Deref,
+            _ => false
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use nom::{
+	IResult, Parser,
+	sequence::pair,
+	combinator::opt,
+	multi::many0
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, fn_call::FnCall, ident::Ident, ws};
+
+/// The attributes known to the compiler, written before a declaration:
+///
+/// | Attribute          | Applies to             | Effect                                         |
+/// |--------------------|------------------------|------------------------------------------------|
+/// | `@align(n)`        | `struct`, `union`, box | Aligns the value to `n` bytes                  |
+/// | `@deprecated(msg)` | Any declaration        | Warns with `msg` wherever the name is used     |
+/// | `@export(name)`    | `fn`, box              | Emits the symbol unmangled, as `name` if given |
+/// | `@inline`          | `fn`                   | Hints the backend to inline calls              |
+/// | `@packed`          | `struct`, `union`      | Lays out fields without padding                |
+///
+/// Attributes are stored as written, and validated against this list by a
+/// later pass.
+pub const KNOWN_ATTRS: [&str; 5] = [
+	"align", "deprecated", "export", "inline", "packed"
+];
+
+/// An attribute such as `@inline` or `@align(8)`.
+#[derive(Debug, Clone)]
+pub struct Attr<'a> {
+	pub ident: Ident<'a>,
+	pub inputs: Vec<Expr<'a>>
+}
+
+impl<'a> Attr<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		pair(
+			Ident::parse.preceded_by(tag("@")),
+			opt(ws(FnCall::parse_inputs)).map(|e| e.unwrap_or_default())
+		)
+			.parse(input)
+			.map(|(input, (ident, inputs))| {
+				(input, Self { ident, inputs })
+			})
+	}
+
+	/// Parses the attributes preceding a declaration.
+	pub fn parse_all(input: &'a str) -> IResult<&str, Vec<Self>, ErrorTree<&str>> {
+		many0(ws(Self::parse))(input)
+	}
+
+	pub fn is_known(&self) -> bool {
+		KNOWN_ATTRS.contains(&self.ident.value)
+	}
+}
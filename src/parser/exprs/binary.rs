@@ -0,0 +1,166 @@
+use nom::{
+	IResult, Parser, Err,
+	branch::alt,
+	combinator::not,
+	error::{ErrorKind, ContextError, ParseError}
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, cast::Cast, postfix::PostfixParser, keyword, ws};
+
+/// Binary operators, from the loosest to the tightest binding:
+///
+/// | Precedence | Operators                   | Associativity |
+/// |------------|-----------------------------|---------------|
+/// | 1          | `or`                        | left          |
+/// | 2          | `and`                       | left          |
+/// | 3          | `==` `!=` `<` `<=` `>` `>=` | none          |
+/// | 4          | `\|`                        | left          |
+/// | 5          | `^`                         | left          |
+/// | 6          | `&`                         | left          |
+/// | 7          | `<<` `>>`                   | left          |
+/// | 8          | `+` `-`                     | left          |
+/// | 9          | `*` `/` `%`                 | left          |
+///
+/// Prefix operators and casts bind tighter than any binary operator, and
+/// parentheses can be used to group an expression explicitly. Comparisons
+/// cannot be chained, `a < b < c` has to be written `a < b and b < c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+	// Logical
+	And,
+	Or,
+
+	// Comparison
+	Eq,
+	Ne,
+	Lt,
+	Le,
+	Gt,
+	Ge,
+
+	// Bitwise
+	BitAnd,
+	BitOr,
+	BitXor,
+	Shl,
+	Shr,
+
+	// Arithmetic
+	Add,
+	Sub,
+	Mul,
+	Div,
+	Rem,
+}
+
+impl BinaryOp {
+	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			keyword("and").map(|_| BinaryOp::And),
+			keyword("or").map(|_| BinaryOp::Or),
+
+			tag("==").map(|_| BinaryOp::Eq),
+			tag("!=").map(|_| BinaryOp::Ne),
+			tag("<<").terminated(not(tag("="))).map(|_| BinaryOp::Shl),
+			tag(">>").terminated(not(tag("="))).map(|_| BinaryOp::Shr),
+			tag("<=").map(|_| BinaryOp::Le),
+			tag(">=").map(|_| BinaryOp::Ge),
+			tag("<").map(|_| BinaryOp::Lt),
+			tag(">").map(|_| BinaryOp::Gt),
+
+			tag("&").terminated(not(tag("="))).map(|_| BinaryOp::BitAnd),
+			tag("|").terminated(not(tag("="))).map(|_| BinaryOp::BitOr),
+			tag("^").terminated(not(tag("="))).map(|_| BinaryOp::BitXor),
+
+			tag("+").terminated(not(tag("="))).map(|_| BinaryOp::Add),
+			tag("-").terminated(not(alt((tag("="), tag(">"))))).map(|_| BinaryOp::Sub),
+			tag("*").terminated(not(tag("="))).map(|_| BinaryOp::Mul),
+			tag("/").terminated(not(tag("="))).map(|_| BinaryOp::Div),
+			tag("%").terminated(not(tag("="))).map(|_| BinaryOp::Rem),
+		))(input)
+	}
+
+	pub fn precedence(self) -> u8 {
+		match self {
+			BinaryOp::Or => 1,
+			BinaryOp::And => 2,
+			BinaryOp::Eq | BinaryOp::Ne
+			| BinaryOp::Lt | BinaryOp::Le
+			| BinaryOp::Gt | BinaryOp::Ge => 3,
+			BinaryOp::BitOr => 4,
+			BinaryOp::BitXor => 5,
+			BinaryOp::BitAnd => 6,
+			BinaryOp::Shl | BinaryOp::Shr => 7,
+			BinaryOp::Add | BinaryOp::Sub => 8,
+			BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => 9,
+		}
+	}
+
+	pub fn is_associative(self) -> bool {
+		self.precedence() != 3
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Binary<'a> {
+	pub op: BinaryOp,
+	pub left: Expr<'a>,
+	pub right: Expr<'a>
+}
+
+impl<'a> Binary<'a> {
+	/// Parses a chain of binary operations, following the precedence table
+	/// of [`BinaryOp`]. A lone operand is returned as is.
+	pub fn parse_expr(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Expr<'a>, ErrorTree<&'a str>> {
+		Self::parse_prec(input, 1, postfix)
+	}
+
+	fn parse_prec(
+		input: &'a str,
+		min_prec: u8,
+		postfix: PostfixParser<'a>
+	) -> IResult<&'a str, Expr<'a>, ErrorTree<&'a str>> {
+		let (mut input, mut left) = ws(|input| Cast::parse_expr(input, postfix))(input)?;
+		let mut last_op: Option<BinaryOp> = None;
+
+		loop {
+			let (next, op) = match ws(BinaryOp::parse)(input) {
+				Ok(x) => x,
+				Err(Err::Error(_)) => break,
+				Err(e) => return Err(e)
+			};
+
+			if op.precedence() < min_prec {
+				break;
+			}
+
+			if let Some(last_op) = last_op {
+				if !last_op.is_associative() && last_op.precedence() == op.precedence() {
+					return Err(Err::Failure(ErrorTree::add_context(
+						input,
+						"comparison operators cannot be chained",
+						ErrorTree::from_error_kind(input, ErrorKind::Verify)
+					)));
+				}
+			}
+
+			let (next, right) = match Self::parse_prec(next, op.precedence() + 1, postfix) {
+				Ok(x) => x,
+				Err(Err::Error(_)) => break,
+				Err(e) => return Err(e)
+			};
+
+			left = Expr::Binary(Box::new(Self { op, left, right }));
+			last_op = Some(op);
+			input = next;
+		}
+
+		Ok((input, left))
+	}
+}
@@ -2,6 +2,7 @@ use nom::{
 	IResult, Parser,
 	sequence::delimited,
 	multi::many0,
+	combinator::opt,
 	branch::alt
 };
 
@@ -10,7 +11,7 @@ use nom_supreme::{
 	error::ErrorTree, ParserExt
 };
 
-use super::{Expr, ws, r#if::If, r#loop::Loop};
+use super::{Expr, ws, r#if::If, r#loop::Loop, r#match::Match};
 
 #[derive(Debug, Clone)]
 pub struct Block<'a> {
@@ -20,16 +21,52 @@ pub struct Block<'a> {
 impl<'a> Block<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		delimited(
-			tag("{"), ws(many0(ws(alt((
-				Expr::parse.terminated(tag(";")),
-				Block::parse.map(|x| Expr::Block(Box::new(x))),
-				If::parse.map(|x| Expr::If(Box::new(x))),
-				Loop::parse.map(|x| Expr::Loop(Box::new(x))),
-			))))), tag("}")
+			tag("{"), ws(many0(ws(Self::parse_stmt))), tag("}")
 		)
 			.parse(input)
 			.map(|(input, exprs)| {
 				(input, Self { exprs })
 			})
 	}
+
+	/// Parses an expression terminated by `;`. A statement starting with a
+	/// block, `if`, `loop` or `match` ends with its block instead, `;` being
+	/// optional, so that `if x { }\n-1;` is two statements.
+	pub fn parse_stmt(input: &'a str) -> IResult<&str, Expr<'a>, ErrorTree<&str>> {
+		alt((
+			Self::parse_block_like.terminated(opt(ws(tag(";")))),
+			Self::parse_decl_stmt,
+			Expr::parse.terminated(ws(tag(";")))
+		))(input)
+	}
+
+	/// Parses a declaration as a statement. Functions and types end with
+	/// their body, or with `;` for opaque structs, so only boxes and type
+	/// aliases need a `;` after them. A builtin call directly followed by `;`
+	/// is taken here too, rather than parsing its inputs again as an
+	/// expression.
+	fn parse_decl_stmt(input: &'a str) -> IResult<&str, Expr<'a>, ErrorTree<&str>> {
+		let (rest, decl) = Expr::parse_decl(input)?;
+
+		let (rest, _) = match decl {
+			Expr::BoxDecl(_) | Expr::TypeDecl(_) => ws(tag(";")).cut().map(Some).parse(rest)?,
+			Expr::Builtin(_) => ws(tag(";")).map(Some).parse(rest)?,
+			_ => opt(ws(tag(";"))).parse(rest)?
+		};
+
+		Ok((rest, decl))
+	}
+
+	/// Parses a block, `if`, `loop` or `match` on its own, without the
+	/// operators or postfixes that could follow it in an expression. Used
+	/// where such an expression ends what it is part of, like a statement
+	/// or a match arm.
+	pub fn parse_block_like(input: &'a str) -> IResult<&str, Expr<'a>, ErrorTree<&str>> {
+		alt((
+			Block::parse.map(|x| Expr::Block(Box::new(x))),
+			If::parse.map(|x| Expr::If(Box::new(x))),
+			Loop::parse.map(|x| Expr::Loop(Box::new(x))),
+			Match::parse.map(|x| Expr::Match(Box::new(x))),
+		))(input)
+	}
 }
@@ -1,6 +1,6 @@
 use nom::{
 	IResult, Parser,
-	sequence::tuple,
+	sequence::{pair, tuple},
 	combinator::opt
 };
 
@@ -9,29 +9,41 @@ use nom_supreme::{
 	tag::complete::tag, ParserExt
 };
 
-use super::{Expr, Vis, ident::Ident, r#type::Type, ws};
+use super::{Expr, attr::Attr, Vis, literals::escape::failure, pattern::Pattern, r#type::Type, keyword, ws};
 
+/// A declaration of one or more boxes, `box x = 1`. The boxes are bound by
+/// an irrefutable pattern, so values can be destructured as in
+/// `box (a, .{ x, y: _ }) = t`, and `mut` applies to every box bound.
 #[derive(Debug, Clone)]
 pub struct BoxDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
 	pub vis: Vis,
 	pub r#mut: bool,
-	pub ident: Ident<'a>,
+	pub pattern: Pattern<'a>,
 	pub r#type: Option<Type<'a>>,
 	pub value: Option<Expr<'a>>
 }
 
 impl<'a> BoxDecl<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		tuple((
-			ws(Vis::parse).terminated(tag("box")),
-			opt(ws(tag("mut"))).map(|e| e.is_some()),
-			ws(Ident::parse),
+		let (rest, (vis, r#mut)) = tuple((
+			ws(Vis::parse).terminated(keyword("box")),
+			opt(ws(keyword("mut"))).map(|e| e.is_some())
+		))(input)?;
+
+		let (rest, pattern) = ws(Pattern::parse_single)(rest)?;
+
+		if !pattern.is_irrefutable() {
+			return Err(failure(input, "box declarations only accept patterns that always match"));
+		}
+
+		pair(
 			opt(ws(Type::parse).preceded_by(tag(":"))),
-			opt(ws(Expr::parse).preceded_by(tag("=")))
-		))
-			.parse(input)
-			.map(|(input, (vis, r#mut, ident, r#type, value))| {
-				(input, Self { vis, r#mut, ident, r#type, value })
+			opt(ws(Expr::parse).cut().preceded_by(tag("=")))
+		)
+			.parse(rest)
+			.map(|(input, (r#type, value))| {
+				(input, Self { attrs: Vec::new(), vis, r#mut, pattern, r#type, value })
 			})
 	}
 }
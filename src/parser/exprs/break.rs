@@ -0,0 +1,60 @@
+use nom::{
+	IResult, Parser,
+	combinator::opt
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree
+};
+
+use super::{Expr, ident::Ident, keyword, ws};
+
+/// Exits the innermost loop, or the loop named `label`, making it evaluate
+/// to `value`.
+///
+/// A lone identifier, as in `break outer`, is stored as the label, and one
+/// followed by an expression, as in `break outer x`, as the label and value.
+/// Whether `outer` names an enclosing loop is only known by a later pass,
+/// which reads a label naming no loop of the same function as the value of
+/// `break` instead.
+#[derive(Debug, Clone)]
+pub struct Break<'a> {
+	pub label: Option<Ident<'a>>,
+	pub value: Option<Expr<'a>>
+}
+
+impl<'a> Break<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (rest, value) = opt(ws(Expr::parse))
+			.preceded_by(keyword("break"))
+			.parse(input)?;
+
+		match value {
+			Some(Expr::Ident(label)) => {
+				opt(ws(Expr::parse))
+					.parse(rest)
+					.map(|(input, value)| (input, Self { label: Some(label), value }))
+			},
+			value => Ok((rest, Self { label: None, value }))
+		}
+	}
+}
+
+/// Skips to the next iteration of the innermost loop, or of the loop named
+/// `label`.
+#[derive(Debug, Clone)]
+pub struct Continue<'a> {
+	pub label: Option<Ident<'a>>
+}
+
+impl<'a> Continue<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		opt(ws(Ident::parse))
+			.preceded_by(keyword("continue"))
+			.parse(input)
+			.map(|(input, label)| {
+				(input, Self { label })
+			})
+	}
+}
@@ -0,0 +1,36 @@
+use nom::sequence::pair;
+
+use nom_supreme::{
+	ParserExt,
+	tag::complete::tag
+};
+
+use super::{Expr, attr::Attr, ident::Ident, ws};
+
+/// A call to a function provided by the compiler, prefixed by `@`. Builtins
+/// are validated by later stages, the known ones being:
+///
+/// | Builtin                  | Value                                             |
+/// |--------------------------|---------------------------------------------------|
+/// | `@discriminant(value)`   | The discriminant of the enum `value`              |
+/// | `@offset(ptr, n)`        | `ptr` moved by `n` items, of the same `PtrKind`   |
+#[derive(Debug, Clone)]
+pub struct Builtin<'a> {
+	pub ident: Ident<'a>,
+	pub inputs: Vec<Expr<'a>>
+}
+
+impl<'a> Builtin<'a> {
+	/// Reads the attributes parsed by [`Expr::parse_decl`] from `input` as a
+	/// builtin call, which they are when no declaration follows a single
+	/// attribute written with parentheses.
+	pub fn from_attrs(input: &'a str, mut attrs: Vec<Attr<'a>>) -> Option<Self> {
+		if attrs.len() != 1 {
+			return None;
+		}
+
+		ws(pair(Ident::parse.preceded_by(tag("@")), ws(tag("("))))(input).ok()?;
+
+		attrs.pop().map(|Attr { ident, inputs }| Self { ident, inputs })
+	}
+}
@@ -0,0 +1,38 @@
+use nom::{
+	IResult, Parser,
+	multi::many0
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree
+};
+
+use super::{Expr, keyword, postfix::PostfixParser, r#type::Type, ws};
+
+/// A conversion of `expr` to `type`, such as `x as u8` or `p as *mut u8`.
+/// Casts bind tighter than binary operators and looser than prefix
+/// operators, so `-a as i64 * b` is `((-a) as i64) * b`. Which conversions
+/// are allowed is left to the type checker.
+#[derive(Debug, Clone)]
+pub struct Cast<'a> {
+	pub expr: Expr<'a>,
+	pub r#type: Type<'a>
+}
+
+impl<'a> Cast<'a> {
+	/// Parses a chain of casts, or a unary expression if there is none.
+	pub fn parse_expr(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Expr<'a>, ErrorTree<&'a str>> {
+		let (input, expr) = Expr::parse_unary(input, postfix)?;
+
+		many0(ws(Type::parse).preceded_by(ws(keyword("as"))))
+			.parse(input)
+			.map(|(input, types)| {
+				let expr = types.into_iter().fold(expr, |expr, r#type| {
+					Expr::Cast(Box::new(Self { expr, r#type }))
+				});
+
+				(input, expr)
+			})
+	}
+}
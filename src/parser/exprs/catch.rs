@@ -0,0 +1,63 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	combinator::opt,
+	multi::many0,
+	sequence::{delimited, pair}
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, binary::Binary, ident::Ident, postfix::PostfixParser, keyword, ws};
+
+#[derive(Debug, Clone)]
+pub enum CatchKind<'a> {
+	/// `expr orelse handler`, giving `handler` if the optional `expr` is
+	/// `null`.
+	Orelse,
+	/// `expr catch |e| handler`, giving `handler` if the error union `expr`
+	/// is an error, optionally bound to `e`.
+	Catch(Option<Ident<'a>>)
+}
+
+impl<'a> CatchKind<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			keyword("orelse").map(|_| CatchKind::Orelse),
+			opt(ws(delimited(tag("|"), ws(Ident::parse), tag("|"))))
+				.preceded_by(keyword("catch"))
+				.map(|x| CatchKind::Catch(x))
+		))(input)
+	}
+}
+
+/// A fallback for an optional or an error union, binding looser than any
+/// binary operator so that `x orelse y + 1` is `x orelse (y + 1)`. Chains
+/// are left-associative.
+#[derive(Debug, Clone)]
+pub struct Catch<'a> {
+	pub kind: CatchKind<'a>,
+	pub expr: Expr<'a>,
+	pub handler: Expr<'a>
+}
+
+impl<'a> Catch<'a> {
+	/// Parses a chain of fallbacks, or a binary expression if there is none.
+	pub fn parse_expr(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Expr<'a>, ErrorTree<&'a str>> {
+		let operand = |input| Binary::parse_expr(input, postfix);
+
+		pair(operand, many0(pair(ws(CatchKind::parse), ws(operand))))
+			.parse(input)
+			.map(|(input, (expr, handlers))| {
+				let expr = handlers.into_iter().fold(expr, |expr, (kind, handler)| {
+					Expr::Catch(Box::new(Self { kind, expr, handler }))
+				});
+
+				(input, expr)
+			})
+	}
+}
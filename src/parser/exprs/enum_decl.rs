@@ -1,6 +1,7 @@
 use nom::{
 	IResult, Parser,
-	sequence::{tuple, delimited},
+	sequence::{delimited, pair, tuple},
+	branch::alt,
 	combinator::opt,
 	multi::separated_list1
 };
@@ -10,48 +11,86 @@ use nom_supreme::{
 	tag::complete::tag, ParserExt
 };
 
-use super::{ident::Ident, ws, struct_decl::Field, vis::Vis};
+use super::{Expr, attr::Attr, ident::Ident, r#type::Type, keyword, ws, struct_decl::{Field, StructDecl}, vis::Vis};
 
+#[derive(Debug, Clone)]
+pub enum EnumPayload<'a> {
+	/// `Rgb { r: u8, g: u8, b: u8 }`
+	Fields(Vec<Field<'a>>),
+	/// `Some(T)`
+	Tuple(Vec<Type<'a>>)
+}
+
+impl<'a> EnumPayload<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			delimited(
+				tag("{"), ws(separated_list1(tag(","), ws(Field::parse))), tag("}")
+			).map(|x| EnumPayload::Fields(x)),
+			delimited(
+				tag("("), ws(separated_list1(tag(","), ws(Type::parse))), tag(")")
+			).map(|x| EnumPayload::Tuple(x))
+		))(input)
+	}
+}
+
+/// A value of an enum, optionally carrying a payload or given an explicit
+/// discriminant, as in `Red = 1`. Values without a discriminant take the
+/// one of the previous value plus one, starting from `0`.
 #[derive(Debug, Clone)]
 pub struct EnumVal<'a> {
 	pub ident: Ident<'a>,
-	pub fields: Option<Vec<Field<'a>>>
+	pub payload: Option<EnumPayload<'a>>,
+	pub discriminant: Option<Expr<'a>>
 }
 
 impl<'a> EnumVal<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		tuple((
 			ws(Ident::parse),
-			opt(ws(delimited(
-				tag("{"), ws(separated_list1(tag(","), ws(Field::parse))), tag("}")
-			)))
+			opt(ws(EnumPayload::parse)),
+			opt(ws(Expr::parse).preceded_by(tag("=")))
 		))
 			.parse(input)
-			.map(|(input, (ident, fields))| {
-				(input, Self { ident, fields })
+			.map(|(input, (ident, payload, discriminant))| {
+				(input, Self { ident, payload, discriminant })
 			})
 	}
 }
 
+/// An enum, whose values can be followed by declarations belonging to the
+/// enum, see [`StructDecl::parse_decls`]. The integer type storing the
+/// discriminant can be given as in `enum(u8) Color { ... }`, and the
+/// discriminant of a value is read with `@discriminant(value)`, or with a
+/// cast such as `Color.Red as u8` when no value has a payload.
 #[derive(Debug, Clone)]
 pub struct EnumDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
 	pub vis: Vis,
+	pub r#type: Option<Type<'a>>,
 	pub ident: Ident<'a>,
-	pub values: Vec<EnumVal<'a>>
+	pub values: Vec<EnumVal<'a>>,
+	pub decls: Vec<Expr<'a>>
 }
 
 impl<'a> EnumDecl<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		tuple((
-			ws(Vis::parse).terminated(tag("enum")),
+			ws(Vis::parse).terminated(keyword("enum")),
+			opt(ws(delimited(tag("("), ws(Type::parse), tag(")")))),
 			ws(Ident::parse),
 			ws(delimited(
-				tag("{"), ws(separated_list1(tag(","), ws(EnumVal::parse))), tag("}")
+				tag("{"),
+				ws(pair(
+					separated_list1(tag(","), ws(EnumVal::parse)).terminated(opt(ws(tag(",")))),
+					StructDecl::parse_decls
+				)),
+				tag("}")
 			))
 		))
 			.parse(input)
-			.map(|(input, (vis, ident, values))| {
-				(input, Self { vis, ident, values })
+			.map(|(input, (vis, r#type, ident, (values, decls)))| {
+				(input, Self { attrs: Vec::new(), vis, r#type, ident, values, decls })
 			})
 	}
 }
\ No newline at end of file
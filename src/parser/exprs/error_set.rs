@@ -0,0 +1,34 @@
+use nom::{
+	IResult, Parser,
+	sequence::delimited,
+	multi::separated_list1
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{ident::Ident, keyword, ws};
+
+/// A set of errors, `error { NotFound, Denied }`, used as the error side of
+/// an error union. Its errors are accessed like enum values, as in
+/// `FileError.NotFound`.
+#[derive(Debug, Clone)]
+pub struct ErrorSet<'a> {
+	pub idents: Vec<Ident<'a>>
+}
+
+impl<'a> ErrorSet<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		ws(delimited(
+			tag("{"), ws(separated_list1(tag(","), ws(Ident::parse))), tag("}")
+		))
+			.preceded_by(keyword("error"))
+			.parse(input)
+			.map(|(input, idents)| {
+				(input, Self { idents })
+			})
+	}
+}
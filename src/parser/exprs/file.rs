@@ -1,15 +1,14 @@
 use nom::{
 	IResult, Parser,
-	multi::many0, branch::alt
+	multi::many0
 };
 
 use nom_supreme::{
 	ParserExt,
-	error::ErrorTree,
-	tag::complete::tag
+	error::ErrorTree
 };
 
-use super::{Expr, ws, fn_decl::FnDecl, struct_decl::StructDecl, enum_decl::EnumDecl};
+use super::{Expr, ws, block::Block};
 
 #[derive(Debug, Clone)]
 pub struct File<'a> {
@@ -18,14 +17,7 @@ pub struct File<'a> {
 
 impl<'a> File<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		ws(many0(
-			ws(alt((
-				Expr::parse.terminated(tag(";")),
-				EnumDecl::parse.map(|x| Expr::EnumDecl(x)),
-				FnDecl::parse.map(|x| Expr::FnDecl(Box::new(x))),
-				StructDecl::parse.map(|x| Expr::StructDecl(x)),
-			)))
-		))
+		ws(many0(ws(Block::parse_stmt)))
 			.all_consuming()
 			.parse(input)
 			.map(|(input, exprs)| {
@@ -1,22 +1,21 @@
 use nom::{
-	IResult, Parser,
-	combinator::recognize,
+	IResult,
 	sequence::delimited,
-	bytes::complete::take_until,
-	multi::separated_list0,
-	branch::alt
+	multi::separated_list0
 };
 
 use nom_supreme::{
-	ParserExt,
 	error::ErrorTree,
-	tag::complete::tag 
+	tag::complete::tag
 };
 
-use crate::parser::exprs::ws;
-
-use super::{Expr, block::Block, path::Path};
+use super::{Expr, ws};
 
+/// A call of `expr` with `inputs`. Method calls such as `value.method(x)`
+/// are calls whose `expr` is a [`Path`](super::path::Path). When `method` is
+/// not a field of `value` but a method declared in its type, `value` is
+/// passed as the `self` parameter, its address being taken if that
+/// parameter is a pointer.
 #[derive(Debug, Clone)]
 pub struct FnCall<'a> {
 	pub expr: Expr<'a>,
@@ -24,40 +23,11 @@ pub struct FnCall<'a> {
 }
 
 impl<'a> FnCall<'a> {
-	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		fn last_paren_group<'a>(input: &'a str, original: &'a str, offset: usize) -> IResult<&'a str, usize, ErrorTree<&'a str>> {
-			let (next_groups, group) = recognize(delimited(
-					tag("("), take_until(")"), tag(")")
-					))(input)?;
-
-			match last_paren_group(next_groups, original, offset + group.len()) {
-				Ok(x) => Ok(x),
-				Err(_) => Ok((input, offset))
-			}
-		}
-
-		let (groups, groups_prefix) = take_until("(")(input)?;
-		let (_, last_group_offset) = last_paren_group(groups, groups, 0)?;
-		let (input, expr) = (
-			&input[groups_prefix.len() + last_group_offset..],
-			&input[..groups_prefix.len() + last_group_offset]
-			);
-
-		let (input, inputs) = ws(delimited(tag("("),
-		ws(separated_list0(
-				tag(","), ws(Expr::parse))),
-				tag(")")
-				))(input)?;
-
-		ws(alt((
-			Block::parse.map(|e| Expr::Block(Box::new(e))),
-			FnCall::parse.map(|e| Expr::FnCall(Box::new(e))),
-			Path::parse.map(|e| Expr::Path(e))
-		)))
-			.all_consuming()
-			.parse(expr)
-			.map(|(_, expr)| {
-				(input, Self { expr, inputs })
-			})
+	/// Parses the parenthesized list of inputs following the called
+	/// expression.
+	pub fn parse_inputs(input: &'a str) -> IResult<&str, Vec<Expr<'a>>, ErrorTree<&str>> {
+		delimited(
+			tag("("), ws(separated_list0(tag(","), ws(Expr::parse))), tag(")")
+		)(input)
 	}
 }
@@ -1,8 +1,8 @@
 use nom::{
 	IResult, Parser,
-	sequence::{tuple, delimited},
-	multi::separated_list1,
-	combinator::opt
+	branch::alt,
+	sequence::{pair, tuple},
+	combinator::{not, opt}
 };
 
 use nom_supreme::{
@@ -11,8 +11,16 @@ use nom_supreme::{
 	tag::complete::tag
 };
 
-use super::{ident::Ident, r#type::Type, block::Block, ws, vis::Vis};
+use super::{Expr, attr::Attr, ident::Ident, r#type::{PtrKind, Type}, block::Block, fn_expr::FnExpr, keyword, ws, vis::Vis};
 
+/// A parameter, `x: int`, or `comp x: int` for a parameter known at compile
+/// time. A `comp` parameter whose type is an interface takes a type
+/// implementing it, see [`InterfaceDecl`](super::interface_decl::InterfaceDecl).
+/// A function declared in a struct or enum whose
+/// first parameter is named `self` is a method, which can be called on a
+/// value as `value.method()`. That parameter can be written `self`, `*self`
+/// or `*mut self`, short for `self: Self`, `self: *Self` and
+/// `self: *mut Self`.
 #[derive(Debug, Clone)]
 pub struct ParamDecl<'a> {
 	pub comp: bool,
@@ -22,20 +30,44 @@ pub struct ParamDecl<'a> {
 
 impl<'a> ParamDecl<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		tuple((
-			opt(tag("comp")).map(|e| e.is_some()),
-			ws(Ident::parse),
-			ws(Type::parse).preceded_by(tag(":"))
-		))
+		alt((
+			Self::parse_self,
+			tuple((
+				opt(keyword("comp")).map(|e| e.is_some()),
+				ws(Ident::parse),
+				ws(Type::parse).preceded_by(tag(":"))
+			))
+				.map(|(comp, ident, r#type)| Self { comp, ident, r#type })
+		))(input)
+	}
+
+	/// Parses the shorthand forms of a `self` parameter.
+	fn parse_self(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		pair(
+			opt(PtrKind::parse),
+			ws(keyword("self")).terminated(not(ws(tag(":"))))
+		)
 			.parse(input)
-			.map(|(input, (comp, ident, r#type))| {
-				(input, Self { comp, ident, r#type })
+			.map(|(input, (ptr_kind, value))| {
+				let r#type = Type::Expr(Expr::Ident(Ident { value: "Self" }));
+
+				let r#type = match ptr_kind {
+					Some(kind) => Type::Ptr(kind, Box::new(r#type)),
+					None => r#type
+				};
+
+				(input, Self { comp: false, ident: Ident { value }, r#type })
 			})
 	}
+
+	pub fn is_self(&self) -> bool {
+		self.ident.value == "self"
+	}
 }
 
 #[derive(Debug, Clone)]
 pub struct FnDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
 	pub vis: Vis,
 	pub ident: Ident<'a>,
 	pub inputs:	Vec<ParamDecl<'a>>,
@@ -46,17 +78,13 @@ pub struct FnDecl<'a> {
 impl<'a> FnDecl<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		tuple((
-			ws(Vis::parse).terminated(tag("fn")),
+			ws(Vis::parse).terminated(keyword("fn")),
 			ws(Ident::parse),
-			opt(ws(delimited(
-				tag("("), separated_list1(tag(","), ws(ParamDecl::parse)), tag(")")
-			))).map(|e| if let Some(e) = e { e } else { Vec::new() }),
-			opt(ws(Type::parse).preceded_by(tag("->"))),
-			ws(Block::parse)
+			FnExpr::parse_body
 		))
 			.parse(input)
-			.map(|(input, (vis, ident, inputs, output, body))| {
-				(input, Self { vis, ident, inputs, output, body })
+			.map(|(input, (vis, ident, FnExpr { inputs, output, body }))| {
+				(input, Self { attrs: Vec::new(), vis, ident, inputs, output, body })
 			})
 	}
 }
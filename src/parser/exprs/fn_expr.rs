@@ -0,0 +1,53 @@
+use nom::{
+	IResult, Parser,
+	sequence::{delimited, pair},
+	multi::separated_list0,
+	combinator::opt
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{block::Block, fn_decl::ParamDecl, r#type::Type, keyword, ws};
+
+/// An anonymous function, `fn(x: int) -> int { ... }`. Named functions
+/// declared with [`FnDecl`](super::fn_decl::FnDecl) share the same
+/// signature and body syntax.
+#[derive(Debug, Clone)]
+pub struct FnExpr<'a> {
+	pub inputs: Vec<ParamDecl<'a>>,
+	pub output: Option<Type<'a>>,
+	pub body: Block<'a>
+}
+
+impl<'a> FnExpr<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		Self::parse_body
+			.preceded_by(keyword("fn"))
+			.parse(input)
+	}
+
+	/// Parses the optional parameters, optional output type and body of a
+	/// function, everything that follows `fn` or the name of the function.
+	pub fn parse_body(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		pair(Self::parse_sig, ws(Block::parse))
+			.parse(input)
+			.map(|(input, ((inputs, output), body))| {
+				(input, Self { inputs, output, body })
+			})
+	}
+
+	/// Parses the optional parameters and optional output type of a
+	/// function.
+	pub fn parse_sig(input: &'a str) -> IResult<&str, (Vec<ParamDecl<'a>>, Option<Type<'a>>), ErrorTree<&str>> {
+		pair(
+			opt(ws(delimited(
+				tag("("), ws(separated_list0(tag(","), ws(ParamDecl::parse))), tag(")")
+			))).map(|e| e.unwrap_or_default()),
+			opt(ws(Type::parse).preceded_by(tag("->")))
+		)(input)
+	}
+}
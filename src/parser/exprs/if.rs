@@ -29,7 +29,7 @@ pub struct If<'a> {
 impl<'a> If<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		tuple((
-			ws(Expr::parse).preceded_by(tag("if")),
+			ws(Expr::parse_cond).preceded_by(tag("if")),
 			ws(Block::parse),
 			opt(alt((
 				ws(Self::parse).map(|e| IfBranch::ElseIf(Box::new(e))),
@@ -0,0 +1,72 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	sequence::{pair, delimited},
+	combinator::opt
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, catch::Catch, postfix::Postfix, range::{Range, RangeKind}, ws};
+
+#[derive(Debug, Clone)]
+pub struct Index<'a> {
+	pub expr: Expr<'a>,
+	pub index: Expr<'a>
+}
+
+/// A slice of `expr` from `start` to `end`, written with a range inside the
+/// brackets. A missing `start` slices from the start of `expr` and a missing
+/// `end` up to its end, so `a[..]` is all of `a`.
+#[derive(Debug, Clone)]
+pub struct Slice<'a> {
+	pub kind: RangeKind,
+	pub expr: Expr<'a>,
+	pub start: Option<Expr<'a>>,
+	pub end: Option<Expr<'a>>
+}
+
+/// The bracketed part of an index or slice expression, before it is
+/// attached to the expression it applies to.
+#[derive(Debug, Clone)]
+pub enum IndexSuffix<'a> {
+	Index(Expr<'a>),
+	Slice(RangeKind, Option<Expr<'a>>, Option<Expr<'a>>)
+}
+
+impl<'a> IndexSuffix<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		delimited(
+			tag("["),
+			ws(alt((
+				pair(RangeKind::parse, opt(ws(|input| Catch::parse_expr(input, Postfix::parse))))
+					.verify(|(kind, end)| *kind == RangeKind::Exclusive || end.is_some())
+					.map(|(kind, end)| IndexSuffix::Slice(kind, None, end)),
+				pair(|input| Range::parse_expr(input, Postfix::parse), opt(ws(tag(".."))))
+					.map(|(index, open)| {
+						match (index, open) {
+							(index, Some(_)) => IndexSuffix::Slice(RangeKind::Exclusive, Some(index), None),
+							(Expr::Range(range), None) => {
+								let Range { kind, start, end } = *range;
+								IndexSuffix::Slice(kind, Some(start), Some(end))
+							},
+							(index, None) => IndexSuffix::Index(index)
+						}
+					})
+			))),
+			tag("]")
+		)
+			.parse(input)
+	}
+
+	pub fn apply(self, expr: Expr<'a>) -> Expr<'a> {
+		match self {
+			IndexSuffix::Index(index) => Expr::Index(Box::new(Index { expr, index })),
+			IndexSuffix::Slice(kind, start, end) => Expr::Slice(Box::new(Slice { kind, expr, start, end }))
+		}
+	}
+}
@@ -0,0 +1,69 @@
+use nom::{
+	IResult, Parser,
+	sequence::{delimited, tuple},
+	multi::many0
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{attr::Attr, fn_decl::ParamDecl, fn_expr::FnExpr, ident::Ident, keyword, r#type::Type, vis::Vis, ws};
+
+/// The signature of a function required by an interface, such as
+/// `fn flush(*mut self);`.
+#[derive(Debug, Clone)]
+pub struct FnSig<'a> {
+	pub ident: Ident<'a>,
+	pub inputs: Vec<ParamDecl<'a>>,
+	pub output: Option<Type<'a>>
+}
+
+impl<'a> FnSig<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Ident::parse).preceded_by(keyword("fn")),
+			FnExpr::parse_sig.terminated(ws(tag(";")))
+		))
+			.parse(input)
+			.map(|(input, (ident, (inputs, output)))| {
+				(input, Self { ident, inputs, output })
+			})
+	}
+}
+
+/// A set of functions a type has to declare, such as
+/// `interface Writer { fn write(*mut self, bytes: []u8) -> !usize; }`.
+/// A struct implements interfaces by listing them after its name,
+/// `struct File: Writer { ... }`, and declaring each of their functions.
+///
+/// Interfaces are used in two ways:
+/// - statically, as the type of a `comp` parameter taking any type
+///   implementing it, as in `fn save(comp W: Writer, w: *mut W)`, each call
+///   being checked and specialized at compile time.
+/// - dynamically, through `dyn Writer`, a pointer to a value of any type
+///   implementing it along with a table of its functions, as C would use a
+///   struct of function pointers.
+#[derive(Debug, Clone)]
+pub struct InterfaceDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
+	pub vis: Vis,
+	pub ident: Ident<'a>,
+	pub fns: Vec<FnSig<'a>>
+}
+
+impl<'a> InterfaceDecl<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Vis::parse).terminated(keyword("interface")),
+			ws(Ident::parse),
+			ws(delimited(tag("{"), ws(many0(ws(FnSig::parse))), tag("}")))
+		))
+			.parse(input)
+			.map(|(input, (vis, ident, fns))| {
+				(input, Self { attrs: Vec::new(), vis, ident, fns })
+			})
+	}
+}
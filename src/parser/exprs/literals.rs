@@ -1,4 +1,5 @@
 pub mod r#bool;
 pub mod r#char;
+pub mod escape;
 pub mod num;
 pub mod string;
\ No newline at end of file
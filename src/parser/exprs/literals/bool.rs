@@ -3,10 +3,9 @@ use nom::{
 	branch::alt
 };
 
-use nom_supreme::{
-	error::ErrorTree,
-	tag::complete::tag
-};
+use nom_supreme::error::ErrorTree;
+
+use crate::parser::exprs::keyword;
 
 #[derive(Debug, Clone)]
 pub struct Bool {
@@ -16,8 +15,8 @@ pub struct Bool {
 impl Bool {
 	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
 		alt((
-			tag("false"),
-			tag("true")
+			keyword("false"),
+			keyword("true")
 		))
 			.parse(input)
 			.map(|(input, value)| {
@@ -1,30 +1,40 @@
 use nom::{
 	IResult, Parser,
-	bytes::complete::take_while_m_n,
-	branch::alt
+	combinator::opt
 };
 
 use nom_supreme::{
-	ParserExt,
 	error::ErrorTree,
 	tag::complete::tag
 };
 
+use super::escape::{failure, take_escaped, unescape};
+
 #[derive(Debug, Clone)]
 pub struct Char<'a> {
-	pub value: &'a str
+	/// The source between the quotes.
+	pub raw: &'a str,
+	/// The value once its escape sequence, if any, is decoded.
+	pub value: char
 }
 
 impl<'a> Char<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		alt((
-			tag("\\n"), tag("\\t"),
-			take_while_m_n(1, 1, |_| true)
-		))
-			.delimited_by(tag("'"))
-			.parse(input)
-			.map(|(input, value)| {
-				(input, Self { value })
-			})
+		let (rest, _) = tag("'")(input)?;
+		let (rest, raw) = take_escaped("'")(rest)?;
+		let (rest, closing) = opt(tag("'")).parse(rest)?;
+
+		if closing.is_none() || raw.contains('\n') {
+			return Err(failure(input, "unterminated char literal"));
+		}
+
+		let value = unescape(raw)?;
+		let mut chars = value.chars();
+
+		match (chars.next(), chars.next()) {
+			(Some(value), None) => Ok((rest, Self { raw, value })),
+			(None, _) => Err(failure(input, "empty char literal")),
+			(Some(_), Some(_)) => Err(failure(input, "char literal must contain exactly one character, use a string literal instead"))
+		}
 	}
-}
\ No newline at end of file
+}
@@ -0,0 +1,104 @@
+use nom::{
+	IResult, Err,
+	character::complete::anychar,
+	error::{ErrorKind, ContextError, ParseError}
+};
+
+use nom_supreme::{
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+/// Builds an unrecoverable error at `location`, so that it is reported
+/// instead of being discarded by the alternatives of enclosing parsers.
+pub fn failure<'a>(location: &'a str, message: &'static str) -> Err<ErrorTree<&'a str>> {
+	Err::Failure(ErrorTree::add_context(
+		location,
+		message,
+		ErrorTree::from_error_kind(location, ErrorKind::Verify)
+	))
+}
+
+/// Parses an escape sequence starting with `\`, shared by string and char
+/// literals:
+///
+/// | Escape     | Value                                    |
+/// |------------|------------------------------------------|
+/// | `\n`       | Line feed                                |
+/// | `\t`       | Tabulation                               |
+/// | `\r`       | Carriage return                          |
+/// | `\\`       | Backslash                                |
+/// | `\"`       | Double quote                             |
+/// | `\'`       | Single quote                             |
+/// | `\0`       | Null                                     |
+/// | `\xNN`     | ASCII character of code `NN`, up to `7F` |
+/// | `\u{NNNN}` | Unicode scalar value of code `NNNN`      |
+pub fn parse_escape(input: &str) -> IResult<&str, char, ErrorTree<&str>> {
+	let (escape, _) = tag("\\")(input)?;
+
+	let (rest, kind) = match anychar::<_, ErrorTree<&str>>(escape) {
+		Ok(x) => x,
+		Err(_) => return Err(failure(input, "unterminated escape sequence"))
+	};
+
+	match kind {
+		'n' => Ok((rest, '\n')),
+		't' => Ok((rest, '\t')),
+		'r' => Ok((rest, '\r')),
+		'\\' => Ok((rest, '\\')),
+		'"' => Ok((rest, '"')),
+		'\'' => Ok((rest, '\'')),
+		'0' => Ok((rest, '\0')),
+		'x' => rest.get(..2)
+			.filter(|x| x.chars().all(|c| c.is_ascii_hexdigit()))
+			.and_then(|x| u8::from_str_radix(x, 16).ok())
+			.filter(|x| x.is_ascii())
+			.map(|x| (&rest[2..], x as char))
+			.ok_or_else(|| failure(input, "invalid hex escape, expected `\\xNN` with `NN` at most `7F`")),
+		'u' => rest.strip_prefix('{')
+			.and_then(|x| x.split_once('}'))
+			.filter(|(x, _)| (1..=6).contains(&x.len()) && x.chars().all(|c| c.is_ascii_hexdigit()))
+			.and_then(|(x, rest)| Some((rest, char::from_u32(u32::from_str_radix(x, 16).ok()?)?)))
+			.ok_or_else(|| failure(input, "invalid unicode escape, expected `\\u{NNNN}` with `NNNN` a unicode scalar value")),
+		_ => Err(failure(input, "unknown escape sequence"))
+	}
+}
+
+/// Decodes the escape sequences of `input`.
+pub fn unescape(input: &str) -> Result<std::string::String, Err<ErrorTree<&str>>> {
+	let mut value = std::string::String::new();
+	let mut rest = input;
+
+	while let Some(offset) = rest.find('\\') {
+		value.push_str(&rest[..offset]);
+
+		let (next, c) = parse_escape(&rest[offset..])?;
+		value.push(c);
+		rest = next;
+	}
+
+	value.push_str(rest);
+
+	Ok(value)
+}
+
+/// Parses characters until `end` or the end of input, skipping over escape
+/// sequences, and returns the consumed slice.
+pub fn take_escaped<'a>(end: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, ErrorTree<&'a str>> {
+	move |input: &'a str| {
+		let mut offset = 0;
+
+		loop {
+			let rest = &input[offset..];
+
+			match rest.chars().next() {
+				Some('\\') => {
+					let (next, _) = parse_escape(rest)?;
+					offset = input.len() - next.len();
+				},
+				Some(c) if !rest.starts_with(end) => offset += c.len_utf8(),
+				_ => return Ok((rest, &input[..offset]))
+			}
+		}
+	}
+}
@@ -1,35 +1,193 @@
-
 use nom::{
 	IResult, Parser,
-	character::complete::digit1,
-	combinator::opt,
-	sequence::tuple
+	branch::alt,
+	bytes::complete::take_while,
+	character::complete::satisfy,
+	combinator::{opt, success},
+	sequence::{pair, tuple}
 };
 
 use nom_supreme::{
 	ParserExt,
 	error::ErrorTree,
-	tag::complete::tag 
+	tag::complete::tag
 };
 
+use super::escape::failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumSuffix {
+	I8,
+	I16,
+	I32,
+	I64,
+	Isize,
+	U8,
+	U16,
+	U32,
+	U64,
+	Usize,
+	F32,
+	F64
+}
+
+impl NumSuffix {
+	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			tag("i8").map(|_| NumSuffix::I8),
+			tag("i16").map(|_| NumSuffix::I16),
+			tag("i32").map(|_| NumSuffix::I32),
+			tag("i64").map(|_| NumSuffix::I64),
+			tag("isize").map(|_| NumSuffix::Isize),
+			tag("u8").map(|_| NumSuffix::U8),
+			tag("u16").map(|_| NumSuffix::U16),
+			tag("u32").map(|_| NumSuffix::U32),
+			tag("u64").map(|_| NumSuffix::U64),
+			tag("usize").map(|_| NumSuffix::Usize),
+			tag("f32").map(|_| NumSuffix::F32),
+			tag("f64").map(|_| NumSuffix::F64)
+		))(input)
+	}
+
+	pub fn is_float(self) -> bool {
+		matches!(self, NumSuffix::F32 | NumSuffix::F64)
+	}
+
+	/// The greatest value a literal with this integer suffix can have.
+	/// Literals are unsigned, so a `negated` one of a signed type can also be
+	/// the magnitude of its minimum, as in `-128i8`. Sizes are assumed to be
+	/// 64 bits wide.
+	pub fn max_int(self, negated: bool) -> u128 {
+		let negated = negated as u128;
+
+		match self {
+			NumSuffix::I8 => i8::MAX as u128 + negated,
+			NumSuffix::I16 => i16::MAX as u128 + negated,
+			NumSuffix::I32 => i32::MAX as u128 + negated,
+			NumSuffix::I64 | NumSuffix::Isize => i64::MAX as u128 + negated,
+			NumSuffix::U8 => u8::MAX as u128,
+			NumSuffix::U16 => u16::MAX as u128,
+			NumSuffix::U32 => u32::MAX as u128,
+			NumSuffix::U64 | NumSuffix::Usize => u64::MAX as u128,
+			NumSuffix::F32 | NumSuffix::F64 => u128::MAX
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumValue {
+	Int(u128),
+	Float(f64)
+}
+
+/// A numeric literal, such as `42`, `0xff_ff`, `1.5e-3` or `1.0f32`.
+///
+/// Integers can be written in hexadecimal (`0x`), octal (`0o`) or binary
+/// (`0b`), and any literal can contain `_` separators after its first
+/// digit. A literal is a float if it has a fractional part, an exponent or a
+/// float suffix.
 #[derive(Debug, Clone)]
 pub struct Num<'a> {
-	pub value: &'a str
+	pub raw: &'a str,
+	pub value: NumValue,
+	pub suffix: Option<NumSuffix>
 }
 
 impl<'a> Num<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		tuple((
-			digit1,
-			opt(
-				tag(".")
-				.terminated(digit1)
+		Self::parse_with(input, false)
+	}
+
+	/// Parses a literal directly following a `-`, which can be the magnitude
+	/// of the minimum of its signed type.
+	pub fn parse_negated(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		Self::parse_with(input, true)
+	}
+
+	fn parse_with(input: &'a str, negated: bool) -> IResult<&'a str, Self, ErrorTree<&'a str>> {
+		let (rest, radix) = alt((
+			tag("0x").map(|_| 16),
+			tag("0o").map(|_| 8),
+			tag("0b").map(|_| 2),
+			success(10)
+		))(input)?;
+
+		let (rest, int) = Self::parse_digits(radix)(rest)
+			.map_err(|e| if radix == 10 { e } else { failure(input, "expected digits after the radix prefix") })?;
+
+		let (rest, (frac, exp)) = if radix == 10 {
+			pair(
+				opt(Self::parse_digits(10).preceded_by(tag("."))),
+				opt(tuple((
+					alt((tag("e"), tag("E"))),
+					opt(alt((tag("+"), tag("-")))),
+					Self::parse_digits(10)
+				)).recognize())
+			)(rest)?
+		} else {
+			(rest, (None, None))
+		};
+
+		let number = &input[..input.len() - rest.len()];
+		let (rest, suffix) = opt(NumSuffix::parse)(rest)?;
+
+		if rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+			return Err(failure(input, "invalid suffix for numeric literal"));
+		}
+
+		let raw = &input[..input.len() - rest.len()];
+		let is_float = frac.is_some() || exp.is_some() || suffix.is_some_and(|x| x.is_float());
+
+		let value = if is_float {
+			if radix != 10 {
+				return Err(failure(input, "float literals must be written in decimal"));
+			}
+
+			if suffix.is_some_and(|x| !x.is_float()) {
+				return Err(failure(input, "float literals cannot have an integer suffix"));
+			}
+
+			let text: std::string::String = number.chars().filter(|&c| c != '_').collect();
+
+			let value: f64 = text.parse().unwrap_or(f64::INFINITY);
+
+			let max = match suffix {
+				Some(NumSuffix::F32) => f32::MAX as f64,
+				_ => f64::MAX
+			};
+
+			if value > max {
+				return Err(failure(input, "float literal is out of range for its type"));
+			}
+
+			NumValue::Float(value)
+		} else {
+			let digits: std::string::String = int.chars().filter(|&c| c != '_').collect();
+
+			let value = match u128::from_str_radix(&digits, radix) {
+				Ok(x) => x,
+				Err(_) => return Err(failure(input, "integer literal is too large"))
+			};
+
+			if suffix.is_some_and(|x| value > x.max_int(negated)) {
+				return Err(failure(input, "integer literal is out of range for its type"));
+			}
+
+			NumValue::Int(value)
+		};
+
+		Ok((rest, Self { raw, value, suffix }))
+	}
+
+	/// Parses digits of `radix` and `_` separators, starting with a digit.
+	fn parse_digits(radix: u32) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, ErrorTree<&'a str>> {
+		move |input| {
+			pair(
+				satisfy(|c| c.is_digit(radix)),
+				take_while(|c: char| c.is_digit(radix) || c == '_')
 			)
-		))
-			.recognize()
-			.parse(input)
-			.map(|(input, value)| {
-				(input, Self { value })
-			})
+				.recognize()
+				.parse(input)
+		}
 	}
-}
\ No newline at end of file
+}
@@ -1,25 +1,126 @@
 use nom::{
 	IResult, Parser,
-	bytes::complete::take_until
+	branch::alt,
+	bytes::complete::take_while,
+	character::complete::{line_ending, space0}
 };
 
 use nom_supreme::{
+	ParserExt,
 	error::ErrorTree,
-	tag::complete::tag, ParserExt
+	tag::complete::tag
 };
 
+use super::escape::{failure, take_escaped, unescape};
+
+#[derive(Debug, Clone)]
+pub enum StringKind {
+	/// `"..."`, with escape sequences.
+	Regular,
+	/// `r"..."`, or `r#"..."#` with any number of `#` to allow `"` in the
+	/// string. Escape sequences are not decoded.
+	Raw,
+	/// A string starting with `"""` and a line break, and ending with `"""`
+	/// on its own line. The indentation of the closing `"""` is stripped from
+	/// every line.
+	MultiLine
+}
+
 #[derive(Debug, Clone)]
 pub struct String<'a> {
-	pub value: &'a str
+	pub kind: StringKind,
+	/// The source between the delimiters.
+	pub raw: &'a str,
+	/// The value once escape sequences are decoded and indentation is
+	/// stripped.
+	pub value: std::string::String
 }
 
 impl<'a> String<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		take_until("\"")
+		alt((
+			Self::parse_multi_line,
+			Self::parse_regular,
+			Self::parse_raw
+		))(input)
+	}
+
+	fn parse_regular(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (input, raw) = take_escaped("\"")
 			.delimited_by(tag("\""))
-			.parse(input)
-			.map(|(input, value)| {
-				(input, Self { value })
-			})
+			.parse(input)?;
+
+		let value = unescape(raw)?;
+
+		Ok((input, Self { kind: StringKind::Regular, raw, value }))
 	}
-}
\ No newline at end of file
+
+	fn parse_raw(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (rest, hashes) = take_while(|c| c == '#')
+			.preceded_by(tag("r"))
+			.terminated(tag("\""))
+			.parse(input)?;
+
+		let closing = format!("\"{hashes}");
+
+		match rest.find(&closing) {
+			Some(offset) => {
+				let raw = &rest[..offset];
+
+				Ok((
+					&rest[offset + closing.len()..],
+					Self { kind: StringKind::Raw, raw, value: raw.to_owned() }
+				))
+			},
+			None => Err(failure(input, "unterminated raw string"))
+		}
+	}
+
+	fn parse_multi_line(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (opening, _) = tag("\"\"\"")(input)?;
+
+		let (body, _) = space0::<_, ErrorTree<&str>>.terminated(line_ending)
+			.parse(opening)
+			.map_err(|_| failure(input, "expected a line break after `\"\"\"`"))?;
+
+		let (rest, body) = take_escaped("\"\"\"")
+			.terminated(tag("\"\"\""))
+			.parse(body)
+			.map_err(|e| match e {
+				nom::Err::Error(_) => failure(input, "unterminated multi-line string"),
+				e => e
+			})?;
+
+		let (lines, indent) = match body.rfind('\n') {
+			Some(offset) => (body[..offset].split('\n').collect(), &body[offset + 1..]),
+			None => (Vec::new(), body)
+		};
+
+		if !indent.chars().all(|c| c == ' ' || c == '\t') {
+			return Err(failure(indent, "expected `\"\"\"` on its own line"));
+		}
+
+		let mut value = std::string::String::new();
+
+		for (i, line) in lines.into_iter().enumerate() {
+			let line = line.strip_suffix('\r').unwrap_or(line);
+
+			if i > 0 {
+				value.push('\n');
+			}
+
+			if line.trim().is_empty() {
+				continue;
+			}
+
+			match line.strip_prefix(indent) {
+				Some(line) => value.push_str(&unescape(line)?),
+				None => return Err(failure(line, "line is less indented than the closing `\"\"\"`"))
+			}
+		}
+
+		let raw = &opening[..opening.len() - rest.len() - 3];
+
+		Ok((rest, Self { kind: StringKind::MultiLine, raw, value }))
+	}
+}
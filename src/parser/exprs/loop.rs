@@ -1,6 +1,7 @@
 use nom::{
 	IResult, Parser,
-	sequence::tuple,
+	sequence::{tuple, pair},
+	combinator::opt,
 	branch::alt
 };
 
@@ -10,27 +11,70 @@ use nom_supreme::{
 	tag::complete::tag
 };
 
-use super::{Expr, ws, block::Block};
+use super::{Expr, ident::Ident, keyword, ws, block::Block};
 
+/// The head of an iterating loop, `item in expr` or `index, item in expr`.
+/// `expr` can be a range, an array or a slice. Such loops are sugar for a
+/// conditional loop over an index, and are lowered to one in later stages.
+#[derive(Debug, Clone)]
+pub struct LoopIter<'a> {
+	pub index: Option<Ident<'a>>,
+	pub item: Ident<'a>,
+	pub expr: Expr<'a>
+}
+
+impl<'a> LoopIter<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			opt(ws(Ident::parse).terminated(tag(","))),
+			ws(Ident::parse).terminated(keyword("in")),
+			ws(Expr::parse_cond)
+		))
+			.parse(input)
+			.map(|(input, (index, item, expr))| {
+				(input, Self { index, item, expr })
+			})
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LoopKind<'a> {
+	/// `loop { }`, runs until exited.
+	Infinite,
+	/// `loop cond { }`, runs while `cond` holds.
+	Cond(Expr<'a>),
+	/// `loop item in expr { }`, runs once per item of `expr`.
+	Iter(LoopIter<'a>)
+}
+
+/// A loop, evaluating to the value given to the `break` exiting it. It can
+/// be named with a `label:` prefix so that `break label` and
+/// `continue label` can refer to it from nested loops.
 #[derive(Debug, Clone)]
 pub struct Loop<'a> {
-	pub cond: Option<Expr<'a>>,
+	pub label: Option<Ident<'a>>,
+	pub kind: LoopKind<'a>,
 	pub body: Block<'a>
 }
 
 impl<'a> Loop<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		alt((
-			ws(Block::parse).map(|x| (None, x)),
-			tuple((
-				ws(Expr::parse).map(|x| Some(x)),
-				ws(Block::parse)
-			))
+		tuple((
+			opt(Ident::parse.terminated(ws(tag(":")))),
+			alt((
+				ws(Block::parse).map(|x| (LoopKind::Infinite, x)),
+				pair(
+					alt((
+						LoopIter::parse.map(|x| LoopKind::Iter(x)),
+						ws(Expr::parse_cond).map(|x| LoopKind::Cond(x))
+					)),
+					ws(Block::parse)
+				)
+			)).preceded_by(keyword("loop"))
 		))
-			.preceded_by(tag("loop"))
 			.parse(input)
-			.map(|(input, (cond, body))| {
-				(input, Self { cond, body })
+			.map(|(input, (label, (kind, body)))| {
+				(input, Self { label, kind, body })
 			})
 	}
 }
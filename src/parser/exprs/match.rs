@@ -0,0 +1,61 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	sequence::{tuple, delimited},
+	combinator::{opt, peek},
+	multi::many0
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, block::Block, pattern::Pattern, keyword, ws};
+
+#[derive(Debug, Clone)]
+pub struct MatchArm<'a> {
+	pub pattern: Pattern<'a>,
+	pub guard: Option<Expr<'a>>,
+	pub body: Expr<'a>
+}
+
+impl<'a> MatchArm<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Pattern::parse),
+			opt(ws(Expr::parse).preceded_by(keyword("if"))),
+			ws(alt((
+				Block::parse_block_like.terminated(opt(ws(tag(",")))),
+				ws(Expr::parse).terminated(alt((tag(","), peek(tag("}")))))
+			))).preceded_by(tag("=>"))
+		))
+			.parse(input)
+			.map(|(input, (pattern, guard, body))| {
+				(input, Self { pattern, guard, body })
+			})
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Match<'a> {
+	pub expr: Expr<'a>,
+	pub arms: Vec<MatchArm<'a>>
+}
+
+impl<'a> Match<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Expr::parse_cond),
+			ws(delimited(
+				tag("{"), ws(many0(ws(MatchArm::parse))), tag("}")
+			))
+		))
+			.preceded_by(keyword("match"))
+			.parse(input)
+			.map(|(input, (expr, arms))| {
+				(input, Self { expr, arms })
+			})
+	}
+}
@@ -1,9 +1,4 @@
-use nom::{
-	IResult, Parser,
-	multi::separated_list1,
-	branch::alt,
-	sequence::pair
-};
+use nom::{IResult, Parser};
 
 use nom_supreme::{
 	ParserExt,
@@ -11,47 +6,22 @@ use nom_supreme::{
 	tag::complete::tag
 };
 
-use super::{Expr, ident::Ident, fn_call::FnCall};
+use super::{Expr, ident::Ident, ws};
 
+/// An access to `ident` in `expr`, `a.b.c` being parsed as `(a.b).c`. When
+/// `expr` is a pointer, the field of the pointed value is accessed, as if
+/// written `expr.*.ident`.
 #[derive(Debug, Clone)]
 pub struct Path<'a> {
-	pub exprs: Vec<Expr<'a>>
+	pub expr: Expr<'a>,
+	pub ident: Ident<'a>
 }
 
 impl<'a> Path<'a> {
-	fn parse_expr(input: &'a str) -> IResult<&str, Expr<'a>, ErrorTree<&str>> {
-		alt((
-			FnCall::parse.map(|x| Expr::FnCall(Box::new(x))),
-			Ident::parse.map(|x| Expr::Ident(x))
-		))(input)
-	}
-
-	pub fn parse_ident(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		separated_list1(tag("."), Ident::parse.map(|x| Expr::Ident(x)))
-			.parse(input)
-			.map(|(input, exprs)| {
-				(input, Self { exprs })
-			})
-	}
-
-	pub fn parse_fn_call(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		pair(
-			FnCall::parse.map(|x| Expr::FnCall(Box::new(x))).terminated(tag(".")),
-			separated_list1(tag("."), Self::parse_expr)
-		).map(|(head, mut tail)| {
-			tail.insert(0, head);
-			tail
-		})
+	/// Parses the `.ident` part following the accessed expression.
+	pub fn parse_ident(input: &'a str) -> IResult<&str, Ident<'a>, ErrorTree<&str>> {
+		ws(Ident::parse)
+			.preceded_by(tag("."))
 			.parse(input)
-			.map(|(input, exprs)| {
-				(input, Self { exprs })
-			})
-	}
-
-	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		alt((
-			Self::parse_ident,
-			Self::parse_fn_call
-		))(input)
 	}
 }
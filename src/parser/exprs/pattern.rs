@@ -0,0 +1,222 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	sequence::{tuple, delimited},
+	combinator::{opt, map_opt},
+	multi::separated_list1
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{
+	literals::{
+		bool::Bool,
+		char::Char,
+		num::Num,
+		string::String
+	},
+	Expr,
+	ident::Ident,
+	path::Path,
+	postfix::Postfix,
+	range::RangeKind,
+	tuple::parse_paren,
+	keyword,
+	ws
+};
+
+#[derive(Debug, Clone)]
+pub struct FieldPat<'a> {
+	pub ident: Ident<'a>,
+	pub pattern: Option<Pattern<'a>>
+}
+
+impl<'a> FieldPat<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Ident::parse),
+			opt(ws(Pattern::parse).preceded_by(tag(":")))
+		))
+			.parse(input)
+			.map(|(input, (ident, pattern))| {
+				(input, Self { ident, pattern })
+			})
+	}
+}
+
+/// A struct, either of a given type (`Point { x, y: 0 }`) or with the type
+/// left to be inferred (`.{ x, y: 0 }`). A field without a pattern binds a
+/// box of the same name.
+#[derive(Debug, Clone)]
+pub struct StructPat<'a> {
+	pub r#type: Option<Ident<'a>>,
+	pub fields: Vec<FieldPat<'a>>
+}
+
+impl<'a> StructPat<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			alt((
+				tag(".").map(|_| None),
+				Ident::parse.map(|x| Some(x))
+			)),
+			ws(delimited(
+				tag("{"), ws(separated_list1(tag(","), ws(FieldPat::parse))), tag("}")
+			))
+		))
+			.parse(input)
+			.map(|(input, (r#type, fields))| {
+				(input, Self { r#type, fields })
+			})
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum PayloadPat<'a> {
+	/// `.Rgb { r, g: 0 }`
+	Fields(Vec<FieldPat<'a>>),
+	/// `.Some(x)`
+	Tuple(Vec<Pattern<'a>>)
+}
+
+impl<'a> PayloadPat<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			delimited(
+				tag("{"), ws(separated_list1(tag(","), ws(FieldPat::parse))), tag("}")
+			).map(|x| PayloadPat::Fields(x)),
+			delimited(
+				tag("("), ws(separated_list1(tag(","), ws(Pattern::parse))), tag(")")
+			).map(|x| PayloadPat::Tuple(x))
+		))(input)
+	}
+}
+
+/// An enum variant, either fully qualified (`Color.Red`) or with the enum
+/// left to be inferred (`.Red`).
+#[derive(Debug, Clone)]
+pub struct VariantPat<'a> {
+	pub r#enum: Option<Expr<'a>>,
+	pub ident: Ident<'a>,
+	pub payload: Option<PayloadPat<'a>>
+}
+
+impl<'a> VariantPat<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(alt((
+				Ident::parse.preceded_by(tag(".")).map(|x| (None, x)),
+				map_opt(
+					Postfix::parse_chain(
+						Ident::parse.map(|x| Expr::Ident(x)),
+						Path::parse_ident.map(|x| Postfix::Path(x))
+					),
+					|e| match e {
+						Expr::Path(path) => Some((Some(path.expr), path.ident)),
+						_ => None
+					}
+				)
+			))),
+			opt(ws(PayloadPat::parse))
+		))
+			.parse(input)
+			.map(|(input, ((r#enum, ident), payload))| {
+				(input, Self { r#enum, ident, payload })
+			})
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct RangePat<'a> {
+	pub kind: RangeKind,
+	pub start: Pattern<'a>,
+	pub end: Pattern<'a>
+}
+
+impl<'a> RangePat<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Pattern::parse_literal),
+			RangeKind::parse,
+			ws(Pattern::parse_literal)
+		))
+			.parse(input)
+			.map(|(input, (start, kind, end))| {
+				(input, Self { kind, start, end })
+			})
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum Pattern<'a> {
+	// Literals
+	Bool(Bool),
+	Char(Char<'a>),
+	/// A number, written with a leading `-` if `negated`, as in `-1`.
+	Num {
+		negated: bool,
+		num: Num<'a>
+	},
+	String(String<'a>),
+
+	// ----------
+	Ident(Ident<'a>),
+	Or(Vec<Pattern<'a>>),
+	Range(Box<RangePat<'a>>),
+	Struct(StructPat<'a>),
+	Tuple(Vec<Pattern<'a>>),
+	Variant(VariantPat<'a>),
+	Wildcard
+}
+
+impl<'a> Pattern<'a> {
+	/// Parses a pattern, alternatives being separated by `|`.
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		separated_list1(tag("|"), ws(Self::parse_single))
+			.parse(input)
+			.map(|(input, mut patterns)| {
+				if patterns.len() == 1 {
+					(input, patterns.remove(0))
+				} else {
+					(input, Pattern::Or(patterns))
+				}
+			})
+	}
+
+	pub fn parse_single(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			keyword("_").map(|_| Pattern::Wildcard),
+			RangePat::parse.map(|x| Pattern::Range(Box::new(x))),
+			Self::parse_literal,
+			parse_paren(Self::parse, |x| Pattern::Tuple(x)),
+			StructPat::parse.map(|x| Pattern::Struct(x)),
+			VariantPat::parse.map(|x| Pattern::Variant(x)),
+			Ident::parse.map(|x| Pattern::Ident(x))
+		))(input)
+	}
+
+	pub fn parse_literal(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			Bool::parse.map(|x| Pattern::Bool(x)),
+			Char::parse.map(|x| Pattern::Char(x)),
+			ws(Num::parse_negated).preceded_by(tag("-")).map(|num| Pattern::Num { negated: true, num }),
+			Num::parse.map(|num| Pattern::Num { negated: false, num }),
+			String::parse.map(|x| Pattern::String(x))
+		))(input)
+	}
+
+	/// Whether the pattern matches any value of the matched type, as
+	/// required where there is no other branch to fall back on.
+	pub fn is_irrefutable(&self) -> bool {
+		match self {
+			Pattern::Ident(_) | Pattern::Wildcard => true,
+			Pattern::Tuple(patterns) => patterns.iter().all(|x| x.is_irrefutable()),
+			Pattern::Struct(x) => x.fields.iter().all(|x| x.pattern.as_ref().is_none_or(|x| x.is_irrefutable())),
+			_ => false
+		}
+	}
+}
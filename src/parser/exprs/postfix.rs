@@ -0,0 +1,104 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	sequence::pair,
+	multi::many0
+};
+
+use nom_supreme::{
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{
+	Expr,
+	fn_call::FnCall,
+	ident::Ident,
+	index::IndexSuffix,
+	path::Path,
+	r#type::Type,
+	struct_val::{FieldVal, StructVal},
+	tuple::TupleField,
+	unary::{Unary, UnaryOp},
+	ws
+};
+
+/// Parses the postfix operations allowed where an expression is parsed,
+/// [`Postfix::parse`] or [`Postfix::parse_cond`]. It is chosen by
+/// [`Expr::parse`] or [`Expr::parse_cond`] and passed down every precedence
+/// level to the operands.
+pub type PostfixParser<'a> = fn(&'a str) -> IResult<&'a str, Postfix<'a>, ErrorTree<&'a str>>;
+
+/// An operation written after the expression it applies to.
+#[derive(Debug, Clone)]
+pub enum Postfix<'a> {
+	/// `expr(a, b)`
+	Call(Vec<Expr<'a>>),
+	/// `expr.ident`
+	Path(Ident<'a>),
+	/// `expr.*`
+	Deref,
+	/// `expr.0`
+	TupleField(usize),
+	/// `expr[i]` or `expr[a..b]`
+	Index(Box<IndexSuffix<'a>>),
+	/// `expr { x: 1 }`
+	StructVal(Vec<FieldVal<'a>>)
+}
+
+impl<'a> Postfix<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			Self::parse_cond,
+			StructVal::parse_field_vals.map(|x| Postfix::StructVal(x))
+		))(input)
+	}
+
+	/// Parses the operations allowed in condition position, everything but
+	/// struct literals.
+	pub fn parse_cond(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			Self::parse_path,
+			tag(".*").map(|_| Postfix::Deref),
+			TupleField::parse_index.map(|x| Postfix::TupleField(x)),
+			IndexSuffix::parse.map(|x| Postfix::Index(Box::new(x)))
+		))(input)
+	}
+
+	/// Parses the operations allowed in a type, calls and paths.
+	pub fn parse_path(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			FnCall::parse_inputs.map(|x| Postfix::Call(x)),
+			Path::parse_ident.map(|x| Postfix::Path(x))
+		))(input)
+	}
+
+	/// Parses `root` followed by any number of operations parsed by
+	/// `postfix`, each one applying to everything on its left.
+	pub fn parse_chain<R, P>(root: R, postfix: P) -> impl FnMut(&'a str) -> IResult<&'a str, Expr<'a>, ErrorTree<&'a str>>
+	where
+		R: Parser<&'a str, Expr<'a>, ErrorTree<&'a str>>,
+		P: Parser<&'a str, Self, ErrorTree<&'a str>>
+	{
+		let mut parser = pair(root, many0(ws(postfix)))
+			.map(|(expr, postfixes)| {
+				postfixes.into_iter().fold(expr, |expr, postfix| postfix.apply(expr))
+			});
+
+		move |input| parser.parse(input)
+	}
+
+	pub fn apply(self, expr: Expr<'a>) -> Expr<'a> {
+		match self {
+			Postfix::Call(inputs) => Expr::FnCall(Box::new(FnCall { expr, inputs })),
+			Postfix::Path(ident) => Expr::Path(Box::new(Path { expr, ident })),
+			Postfix::Deref => Expr::Unary(Box::new(Unary { op: UnaryOp::Deref, expr })),
+			Postfix::TupleField(index) => Expr::TupleField(Box::new(TupleField { expr, index })),
+			Postfix::Index(index) => (*index).apply(expr),
+			Postfix::StructVal(field_vals) => Expr::StructVal(Box::new(StructVal {
+				expr: Some(Type::Expr(expr)),
+				field_vals
+			}))
+		}
+	}
+}
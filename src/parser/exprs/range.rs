@@ -0,0 +1,55 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	sequence::pair,
+	combinator::opt
+};
+
+use nom_supreme::{
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, catch::Catch, postfix::PostfixParser, ws};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeKind {
+	/// `a..b`, from `a` included to `b` excluded.
+	Exclusive,
+	/// `a..=b`, from `a` to `b` both included.
+	Inclusive
+}
+
+impl RangeKind {
+	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			tag("..=").map(|_| RangeKind::Inclusive),
+			tag("..").map(|_| RangeKind::Exclusive)
+		))(input)
+	}
+}
+
+/// A range of values, binding looser than any binary operator or fallback so
+/// that `0..n + 1` is `0..(n + 1)`.
+#[derive(Debug, Clone)]
+pub struct Range<'a> {
+	pub kind: RangeKind,
+	pub start: Expr<'a>,
+	pub end: Expr<'a>
+}
+
+impl<'a> Range<'a> {
+	/// Parses a range, or a fallback if there is no range operator.
+	pub fn parse_expr(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Expr<'a>, ErrorTree<&'a str>> {
+		let operand = |input| Catch::parse_expr(input, postfix);
+
+		pair(operand, opt(pair(ws(RangeKind::parse), operand)))
+			.parse(input)
+			.map(|(input, (start, range))| {
+				match range {
+					Some((kind, end)) => (input, Expr::Range(Box::new(Self { kind, start, end }))),
+					None => (input, start)
+				}
+			})
+	}
+}
@@ -1,8 +1,9 @@
 use nom::{
 	IResult, Parser,
-	sequence::{tuple, delimited},
-	multi::separated_list1,
-	branch::alt
+	sequence::{delimited, pair, tuple},
+	multi::{many0, separated_list0, separated_list1},
+	branch::alt,
+	combinator::opt
 };
 
 use nom_supreme::{
@@ -11,46 +12,81 @@ use nom_supreme::{
 	tag::complete::tag
 };
 
-use super::{ident::Ident, r#type::Type, ws};
+use super::{Expr, attr::Attr, block::Block, ident::Ident, r#type::Type, vis::Vis, keyword, ws};
 
+/// A field, such as `pub x: int = 0`. Fields with a default value can be
+/// omitted from struct literals.
 #[derive(Debug, Clone)]
 pub struct Field<'a> {
+	pub vis: Vis,
 	pub ident: Ident<'a>,
-	pub r#type: Type<'a>
+	pub r#type: Type<'a>,
+	pub value: Option<Expr<'a>>
 }
 
 impl<'a> Field<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		tuple((
+			ws(Vis::parse),
 			ws(Ident::parse).terminated(tag(":")),
-			ws(Type::parse)
+			ws(Type::parse),
+			opt(ws(Expr::parse).preceded_by(tag("=")))
 		))
 			.parse(input)
-			.map(|(input, (ident, r#type))| {
-				(input, Self { ident, r#type })
+			.map(|(input, (vis, ident, r#type, value))| {
+				(input, Self { vis, ident, r#type, value })
 			})
 	}
 }
 
+/// A struct, `struct Point { x: int, y: int }`, or an opaque struct,
+/// `struct Handle;`. Fields can be followed by declarations belonging to the
+/// struct, see [`StructDecl::parse_decls`]. The interfaces implemented by the
+/// struct are listed after its name, as in `struct File: Writer, Reader`.
 #[derive(Debug, Clone)]
 pub struct StructDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
+	pub vis: Vis,
 	pub ident: Ident<'a>,
-	pub fields: Option<Vec<Field<'a>>>
+	pub interfaces: Vec<Type<'a>>,
+	pub fields: Option<Vec<Field<'a>>>,
+	pub decls: Vec<Expr<'a>>
 }
 
 impl<'a> StructDecl<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
 		tuple((
+			ws(Vis::parse).terminated(keyword("struct")),
 			ws(Ident::parse),
+			opt(ws(separated_list1(tag(","), ws(Type::parse))).preceded_by(tag(":")))
+				.map(|e| e.unwrap_or_default()),
 			ws(alt((
-				delimited(tag("{"), ws(separated_list1(tag(","), ws(Field::parse))), tag("}")).map(|x| Some(x)),
-				tag(";").map(|_| None)
+				delimited(
+					tag("{"),
+					ws(pair(
+						separated_list0(tag(","), ws(Field::parse)).terminated(opt(ws(tag(",")))),
+						Self::parse_decls
+					)),
+					tag("}")
+				).map(|(fields, decls)| (Some(fields), decls)),
+				tag(";").map(|_| (None, Vec::new()))
 			)))
 		))
-			.preceded_by(tag("struct"))
 			.parse(input)
-			.map(|(input, (ident, fields))| {
-				(input, Self { ident, fields })
+			.map(|(input, (vis, ident, interfaces, (fields, decls)))| {
+				(input, Self { attrs: Vec::new(), vis, ident, interfaces, fields, decls })
 			})
 	}
+
+	/// Parses the declarations of a struct or enum body, written after its
+	/// fields or values like statements: functions, boxes and nested types.
+	/// They are namespaced by the type, `Point.new()` calling the `new`
+	/// function declared in `Point`.
+	pub fn parse_decls(input: &'a str) -> IResult<&str, Vec<Expr<'a>>, ErrorTree<&str>> {
+		many0(ws(Block::parse_stmt.verify(Self::is_decl)))(input)
+	}
+
+	pub fn is_decl(expr: &Expr) -> bool {
+		matches!(expr, Expr::BoxDecl(_) | Expr::EnumDecl(_) | Expr::FnDecl(_) | Expr::InterfaceDecl(_) | Expr::StructDecl(_) | Expr::TypeDecl(_) | Expr::UnionDecl(_))
+	}
 }
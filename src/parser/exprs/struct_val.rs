@@ -2,7 +2,6 @@ use nom::{
 	IResult, Parser,
 	combinator::opt,
 	sequence::{tuple, delimited},
-	branch::alt,
 	multi::separated_list0
 };
 
@@ -33,6 +32,9 @@ impl<'a> FieldVal<'a> {
 	}
 }
 
+/// A struct literal, either of a given type (`Point { x: 1 }`) or with the
+/// type left to be inferred (`.{ x: 1 }`). The typed form is parsed as a
+/// postfix operation on the type expression.
 #[derive(Debug, Clone)]
 pub struct StructVal<'a> {
 	pub expr: Option<Type<'a>>,
@@ -41,18 +43,17 @@ pub struct StructVal<'a> {
 
 impl<'a> StructVal<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		tuple((
-			ws(alt((
-				Type::parse.map(|e| Some(e)),
-				tag(".").map(|_| None)
-			))),
-			ws(delimited(
-				tag("{"), ws(separated_list0(tag(","), ws(FieldVal::parse))), tag("}")
-			))
-		))
+		ws(Self::parse_field_vals)
+			.preceded_by(tag("."))
 			.parse(input)
-			.map(|(input, (expr, field_vals))| {
-				(input, Self { expr, field_vals })
+			.map(|(input, field_vals)| {
+				(input, Self { expr: None, field_vals })
 			})
 	}
+
+	pub fn parse_field_vals(input: &'a str) -> IResult<&str, Vec<FieldVal<'a>>, ErrorTree<&str>> {
+		delimited(
+			tag("{"), ws(separated_list0(tag(","), ws(FieldVal::parse))), tag("}")
+		)(input)
+	}
 }
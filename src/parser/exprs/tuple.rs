@@ -0,0 +1,71 @@
+use nom::{
+	IResult, Parser,
+	character::complete::digit1,
+	combinator::{map_res, opt},
+	multi::separated_list0,
+	sequence::{delimited, pair}
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, ws};
+
+/// A tuple literal, `(1, true)`. A tuple with a single value needs a
+/// trailing comma, `(x,)`, as `(x)` is `x` between parentheses.
+#[derive(Debug, Clone)]
+pub struct Tuple<'a> {
+	pub exprs: Vec<Expr<'a>>
+}
+
+impl<'a> Tuple<'a> {
+	/// Parses a tuple literal, or an expression between parentheses.
+	pub fn parse_expr(input: &'a str) -> IResult<&str, Expr<'a>, ErrorTree<&str>> {
+		parse_paren(Expr::parse, |exprs| Expr::Tuple(Box::new(Self { exprs })))(input)
+	}
+}
+
+/// An access to the value at `index` in the tuple `expr`, `t.0`.
+#[derive(Debug, Clone)]
+pub struct TupleField<'a> {
+	pub expr: Expr<'a>,
+	pub index: usize
+}
+
+impl<'a> TupleField<'a> {
+	/// Parses the `.index` part following the accessed expression.
+	pub fn parse_index(input: &'a str) -> IResult<&str, usize, ErrorTree<&str>> {
+		map_res(digit1, str::parse)
+			.preceded_by(tag("."))
+			.parse(input)
+	}
+}
+
+/// Parses a comma separated list of `item` between parentheses, shared by
+/// tuple literals, types and patterns. A single item without a trailing
+/// comma is returned as is, any other list is passed to `tuple`.
+pub fn parse_paren<'a, O, F, T>(item: F, tuple: T) -> impl FnMut(&'a str) -> IResult<&'a str, O, ErrorTree<&'a str>>
+where
+	F: Parser<&'a str, O, ErrorTree<&'a str>>,
+	T: Fn(Vec<O>) -> O
+{
+	let mut parser = delimited(
+		tag("("),
+		ws(pair(separated_list0(tag(","), ws(item)), opt(tag(","))))
+			.verify(|(items, comma)| !(items.is_empty() && comma.is_some())),
+		tag(")")
+	);
+
+	move |input| {
+		parser.parse(input).map(|(input, (mut items, comma))| {
+			if items.len() == 1 && comma.is_none() {
+				(input, items.remove(0))
+			} else {
+				(input, tuple(items))
+			}
+		})
+	}
+}
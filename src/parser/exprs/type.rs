@@ -1,8 +1,9 @@
 use nom::{
 	IResult, Parser,
 	branch::alt,
-	sequence::tuple,
-	combinator::opt
+	sequence::{delimited, pair},
+	combinator::opt,
+	multi::separated_list0
 };
 
 use nom_supreme::{
@@ -11,38 +12,158 @@ use nom_supreme::{
 	tag::complete::tag
 };
 
-use super::{Expr, block::Block, enum_decl::EnumDecl, fn_call::FnCall, struct_decl::StructDecl, path::Path, ws};
+use super::{Expr, binary::Binary, block::Block, enum_decl::EnumDecl, error_set::ErrorSet, ident::Ident, keyword, postfix::Postfix, struct_decl::StructDecl, tuple::parse_paren, union_decl::UnionDecl, ws};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PtrKind {
 	Const,
 	Mutable
 }
 
+impl PtrKind {
+	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			ws(keyword("mut")).preceded_by(tag("*")).map(|_| PtrKind::Mutable),
+			tag("*").map(|_| PtrKind::Const)
+		))(input)
+	}
+}
+
 #[derive(Debug, Clone)]
-pub struct Type<'a> {
-	pub ptr_kind: Option<PtrKind>,
-	pub value: Expr<'a>
+pub enum ArrayKind<'a> {
+	/// `[N]T`, `N` values stored in place, `N` being known at compile time.
+	Array(Expr<'a>),
+	/// `[]T` or `[]mut T`, a pointer to values along with their count.
+	Slice(PtrKind),
+	/// `[*]T` or `[*]mut T`, a pointer to an unknown count of values, which
+	/// unlike `*T` can be indexed and offset.
+	ManyPtr(PtrKind)
+}
+
+/// A type. Prefixes apply to the type on their right, `?*T` being an
+/// optional pointer to `T`, and `E!T` applies to everything on both sides
+/// of the `!`.
+#[derive(Debug, Clone)]
+pub enum Type<'a> {
+	/// A named type such as `int` or `std.mem.Allocator`, or a type declared
+	/// in place such as `struct Point { x: int }`. Types are values known at
+	/// compile time, so a type can also be the result of a call to a function
+	/// returning one, as in `List(int)`, or of a block evaluated at compile
+	/// time.
+	Expr(Expr<'a>),
+	/// `*T` or `*mut T`
+	Ptr(PtrKind, Box<Type<'a>>),
+	/// `dyn Writer`, a value of any type implementing the interface, called
+	/// through a table of its functions.
+	Dyn(Box<Type<'a>>),
+	/// An array, slice or many-item pointer, optionally terminated by a
+	/// `sentinel` value following the last item, as in `[:0]u8` for C
+	/// strings.
+	Array {
+		kind: ArrayKind<'a>,
+		sentinel: Option<Expr<'a>>,
+		r#type: Box<Type<'a>>
+	},
+	/// `fn(int, *u8) -> bool`, the type of functions taking and returning
+	/// the given types. Its values are pointers to code, emitted as C
+	/// function pointers, so any function with a matching signature, named
+	/// or anonymous, can be assigned to it.
+	Fn {
+		inputs: Vec<Type<'a>>,
+		output: Option<Box<Type<'a>>>
+	},
+	/// `(A, B)`, the type of tuple literals. `()` has a single value, `()`,
+	/// and `(T)` is `T` between parentheses.
+	Tuple(Vec<Type<'a>>),
+	/// `?T`, either a `T` or `null`. `?*T` is a nullable pointer.
+	Optional(Box<Type<'a>>),
+	/// `E!T`, either an error of the set `E` or a `T`. The set is inferred
+	/// from the body of the function returning it when written `!T`.
+	ErrorUnion {
+		error: Option<Box<Type<'a>>>,
+		value: Box<Type<'a>>
+	}
 }
 
 impl<'a> Type<'a> {
 	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
-		tuple((
-			ws(opt(alt((
-				tag("*mut").map(|_| PtrKind::Mutable),
-				tag("*").map(|_| PtrKind::Const)
-			)))),
-			ws(alt((
-				Block::parse.map_res::<_, _, ErrorTree<&str>>(|e| Ok(Expr::Block(Box::new(e)))),
-				EnumDecl::parse.map_res::<_, _, ErrorTree<&str>>(|e| Ok(Expr::EnumDecl(e))),
-				FnCall::parse.map_res::<_, _, ErrorTree<&str>>(|e| Ok(Expr::FnCall(Box::new(e)))),
-				Path::parse.map_res::<_, _, ErrorTree<&str>>(|e| Ok(Expr::Path(e))),
-				StructDecl::parse.map_res::<_, _, ErrorTree<&str>>(|e| Ok(Expr::StructDecl(e)))
-			)))
-		))
+		pair(
+			ws(Self::parse_prefixed),
+			opt(ws(Self::parse_prefixed).preceded_by(tag("!")))
+		)
 			.parse(input)
-			.map(|(input, (ptr_kind, value))| {
-				(input, Self { ptr_kind, value })
+			.map(|(input, (r#type, value))| {
+				match value {
+					Some(value) => (input, Type::ErrorUnion { error: Some(Box::new(r#type)), value: Box::new(value) }),
+					None => (input, r#type)
+				}
 			})
 	}
-}
\ No newline at end of file
+
+	fn parse_prefixed(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			ws(Self::parse_prefixed).preceded_by(tag("?")).map(|x| Type::Optional(Box::new(x))),
+			ws(Self::parse_prefixed).preceded_by(tag("!")).map(|x| Type::ErrorUnion { error: None, value: Box::new(x) }),
+			pair(PtrKind::parse, ws(Self::parse_prefixed)).map(|(kind, x)| Type::Ptr(kind, Box::new(x))),
+			ws(Self::parse_prefixed).preceded_by(keyword("dyn")).map(|x| Type::Dyn(Box::new(x))),
+			Self::parse_array,
+			Self::parse_fn,
+			parse_paren(Self::parse, |x| Type::Tuple(x)),
+			alt((
+				Block::parse.map(|x| Expr::Block(Box::new(x))),
+				EnumDecl::parse.map(|x| Expr::EnumDecl(Box::new(x))),
+				ErrorSet::parse.map(|x| Expr::ErrorSet(x)),
+				StructDecl::parse.map(|x| Expr::StructDecl(x)),
+				UnionDecl::parse.map(|x| Expr::UnionDecl(Box::new(x))),
+				Postfix::parse_chain(Ident::parse.map(|x| Expr::Ident(x)), Postfix::parse_path)
+			)).map(|x| Type::Expr(x))
+		))(input)
+	}
+
+	fn parse_array(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		let (rest, (len, sentinel)) = delimited(
+			tag("["),
+			ws(pair(
+				opt(alt((
+					(|input| Binary::parse_expr(input, Postfix::parse)).map(|x| Some(x)),
+					tag("*").map(|_| None)
+				))),
+				opt(ws(|input| Binary::parse_expr(input, Postfix::parse)).preceded_by(ws(tag(":"))))
+			)),
+			tag("]")
+		)(input)?;
+
+		let (rest, kind) = match len {
+			Some(Some(len)) => (rest, ArrayKind::Array(len)),
+			len => {
+				let (rest, r#mut) = opt(ws(keyword("mut")))(rest)?;
+				let ptr_kind = if r#mut.is_some() { PtrKind::Mutable } else { PtrKind::Const };
+
+				match len {
+					Some(_) => (rest, ArrayKind::ManyPtr(ptr_kind)),
+					None => (rest, ArrayKind::Slice(ptr_kind))
+				}
+			}
+		};
+
+		ws(Self::parse_prefixed)
+			.parse(rest)
+			.map(|(input, r#type)| {
+				(input, Type::Array { kind, sentinel, r#type: Box::new(r#type) })
+			})
+	}
+
+	fn parse_fn(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		pair(
+			ws(delimited(
+				tag("("), ws(separated_list0(tag(","), ws(Self::parse))), tag(")")
+			)),
+			opt(ws(Self::parse).preceded_by(tag("->")))
+		)
+			.preceded_by(keyword("fn"))
+			.parse(input)
+			.map(|(input, (inputs, output))| {
+				(input, Type::Fn { inputs, output: output.map(Box::new) })
+			})
+	}
+}
@@ -0,0 +1,41 @@
+use nom::{
+	IResult, Parser,
+	sequence::tuple,
+	combinator::opt
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{attr::Attr, ident::Ident, keyword, r#type::Type, vis::Vis, ws};
+
+/// A name for a type, `type Size = u64;`. An alias is interchangeable with
+/// the type it names, while a distinct type, `type UserId = distinct u64;`,
+/// has the same representation but is a different type, converted to and
+/// from the original with a cast.
+#[derive(Debug, Clone)]
+pub struct TypeDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
+	pub vis: Vis,
+	pub ident: Ident<'a>,
+	pub distinct: bool,
+	pub r#type: Type<'a>
+}
+
+impl<'a> TypeDecl<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Vis::parse).terminated(keyword("type")),
+			ws(Ident::parse).terminated(tag("=")),
+			opt(ws(keyword("distinct"))).map(|e| e.is_some()),
+			ws(Type::parse)
+		))
+			.parse(input)
+			.map(|(input, (vis, ident, distinct, r#type))| {
+				(input, Self { attrs: Vec::new(), vis, ident, distinct, r#type })
+			})
+	}
+}
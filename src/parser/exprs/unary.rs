@@ -0,0 +1,65 @@
+use nom::{
+	IResult, Parser,
+	branch::alt,
+	combinator::not
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, literals::num::Num, postfix::PostfixParser, r#type::PtrKind, keyword, ws};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+	Neg,
+	Not,
+	/// `&x` or `&mut x`, giving a pointer of the matching kind.
+	AddrOf(PtrKind),
+	/// `*p`, also written `p.*`.
+	Deref,
+	/// `try x`, returning the error from the enclosing function if the error
+	/// union `x` is one, or giving its value otherwise.
+	Try
+}
+
+impl UnaryOp {
+	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
+		alt((
+			tag("-").terminated(not(tag(">"))).map(|_| UnaryOp::Neg),
+			tag("!").terminated(not(tag("="))).map(|_| UnaryOp::Not),
+			ws(keyword("mut")).preceded_by(tag("&")).map(|_| UnaryOp::AddrOf(PtrKind::Mutable)),
+			tag("&").map(|_| UnaryOp::AddrOf(PtrKind::Const)),
+			tag("*").map(|_| UnaryOp::Deref),
+			keyword("try").map(|_| UnaryOp::Try)
+		))(input)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Unary<'a> {
+	pub op: UnaryOp,
+	pub expr: Expr<'a>
+}
+
+impl<'a> Unary<'a> {
+	pub fn parse(input: &'a str, postfix: PostfixParser<'a>) -> IResult<&'a str, Self, ErrorTree<&'a str>> {
+		let (rest, op) = ws(UnaryOp::parse)(input)?;
+
+		// A negated literal is checked knowing it, for `-128i8` to be in range
+		// while `128i8` and `-128i8.x` are not.
+		if op == UnaryOp::Neg {
+			if let Ok((rest, num)) = ws(Num::parse_negated).terminated(not(postfix)).parse(rest) {
+				return Ok((rest, Self { op, expr: Expr::Num(num) }));
+			}
+		}
+
+		ws(|input| Expr::parse_unary(input, postfix))
+			.parse(rest)
+			.map(|(input, expr)| {
+				(input, Self { op, expr })
+			})
+	}
+}
@@ -0,0 +1,51 @@
+use nom::{
+	IResult, Parser,
+	sequence::{delimited, pair, tuple},
+	multi::separated_list1,
+	combinator::opt
+};
+
+use nom_supreme::{
+	ParserExt,
+	error::ErrorTree,
+	tag::complete::tag
+};
+
+use super::{Expr, attr::Attr, ident::Ident, keyword, struct_decl::{Field, StructDecl}, vis::Vis, ws};
+
+/// An untagged union, `union Value { i: i64, f: f64 }`, whose fields all
+/// share the same memory. Reading a field other than the last one written
+/// reinterprets its bytes. An `extern union` is laid out as in C, so that it
+/// can describe a union declared in a C header. Fields can be followed by
+/// declarations belonging to the union, see [`StructDecl::parse_decls`].
+#[derive(Debug, Clone)]
+pub struct UnionDecl<'a> {
+	pub attrs: Vec<Attr<'a>>,
+	pub vis: Vis,
+	pub r#extern: bool,
+	pub ident: Ident<'a>,
+	pub fields: Vec<Field<'a>>,
+	pub decls: Vec<Expr<'a>>
+}
+
+impl<'a> UnionDecl<'a> {
+	pub fn parse(input: &'a str) -> IResult<&str, Self, ErrorTree<&str>> {
+		tuple((
+			ws(Vis::parse),
+			opt(ws(keyword("extern"))).map(|e| e.is_some()).terminated(keyword("union")),
+			ws(Ident::parse),
+			ws(delimited(
+				tag("{"),
+				ws(pair(
+					separated_list1(tag(","), ws(Field::parse)).terminated(opt(ws(tag(",")))),
+					StructDecl::parse_decls
+				)),
+				tag("}")
+			))
+		))
+			.parse(input)
+			.map(|(input, (vis, r#extern, ident, (fields, decls)))| {
+				(input, Self { attrs: Vec::new(), vis, r#extern, ident, fields, decls })
+			})
+	}
+}
@@ -3,10 +3,9 @@ use nom::{
 	combinator::opt
 };
 
-use nom_supreme::{
-	error::ErrorTree,
-	tag::complete::tag
-};
+use nom_supreme::error::ErrorTree;
+
+use super::keyword;
 
 #[derive(Debug, Clone)]
 pub enum Vis {
@@ -16,6 +15,6 @@ pub enum Vis {
 
 impl Vis {
 	pub fn parse(input: &str) -> IResult<&str, Self, ErrorTree<&str>> {
-		opt(tag("pub")).map(|e| if e.is_some() { Vis::Public } else { Vis::Private }).parse(input)
+		opt(keyword("pub")).map(|e| if e.is_some() { Vis::Public } else { Vis::Private }).parse(input)
 	}
 }